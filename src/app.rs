@@ -20,8 +20,8 @@ pub struct App {
 
 impl App {
     // create a new application instance
-    pub fn new() -> Self {
-        let interface = Interface::new();
+    pub fn new(seed: Option<u64>) -> Self {
+        let interface = Interface::new(seed);
         let tick_rate = Duration::from_millis(250);
         App {
             is_running: true,
@@ -3,4 +3,8 @@ use clap::Parser;
 #[derive(Parser)]
 #[clap(author, version, about)]
 #[clap(propagate_version = true)]
-pub struct Args {}
+pub struct Args {
+    /// Seed for shuffling, the same seed always deals the same game
+    #[clap(long)]
+    pub seed: Option<u64>,
+}
@@ -0,0 +1,73 @@
+use crate::engine::{
+    card::{Card, Suit},
+    game::GameState,
+    table::{Bid, Seat},
+};
+use std::fmt::{Display, Formatter};
+
+// a decision made by a player, user or bot, applied to the game with Game::apply
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameAction {
+    // throw in a hand with no face cards or aces, or keep it
+    CallMisdeal(bool),
+    // swap a farmer's hand with the kitty, or keep it
+    SwapFarmersHand(bool),
+    OrderUp { alone: bool },
+    Pass,
+    CallSuit { suit: Suit, alone: bool },
+    Bid(Bid),
+    NameTrump { suit: Suit, alone: bool },
+    CallAce(Suit),
+    // the index of the card in hand
+    Discard(usize),
+    PassToLoner(usize),
+    DefendAlone(bool),
+    PlayCard(usize),
+    ClaimTricks,
+    // may be called by anyone during play, not just the current player
+    CallRenege,
+}
+
+// something that happened while applying an action or advancing the game
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    // a line for the game log
+    Message(String),
+    StateChanged(GameState),
+    CardPlayed { seat: Seat, card: Card },
+    TrickTaken { seat: Seat },
+}
+
+// why an action was rejected, the game is left untouched
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RuleError {
+    NotYourTurn,
+    NotAllowedNow,
+    DealerStuck,
+    TurnedDown(Suit),
+    SuitNotAllowed(Suit),
+    LonersNotAllowed,
+    NoSuchCard(usize),
+    MustFollow(Suit),
+    BidNotAllowed(Bid),
+    ClaimNotOnLead,
+    ClaimRejected,
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RuleError::NotYourTurn => write!(f, "It's not your turn"),
+            RuleError::NotAllowedNow => write!(f, "That can't be done right now"),
+            RuleError::DealerStuck => write!(f, "The dealer is stuck and must call a suit"),
+            RuleError::TurnedDown(suit) => write!(f, "{} was turned down", suit.get_name()),
+            RuleError::SuitNotAllowed(suit) => write!(f, "{} can't be called", suit.get_name()),
+            RuleError::LonersNotAllowed => write!(f, "Going alone isn't allowed"),
+            RuleError::NoSuchCard(card_idx) => write!(f, "There is no card {}", card_idx + 1),
+            RuleError::MustFollow(suit) => write!(f, "You must follow {}", suit.get_name()),
+            RuleError::BidNotAllowed(bid) => write!(f, "A bid of {} is not allowed", bid),
+            RuleError::ClaimNotOnLead => write!(f, "Tricks can only be claimed on lead"),
+            RuleError::ClaimRejected => write!(f, "The claim was rejected, play on"),
+        }
+    }
+}
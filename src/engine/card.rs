@@ -1,4 +1,6 @@
-use rand::seq::SliceRandom;
+use rand::{seq::SliceRandom, Rng};
+
+pub static SUIT_VARIANTS: &[Suit] = &[Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades];
 
 // card color repr
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -14,6 +16,8 @@ pub enum Suit {
     Diamonds,
     Clubs,
     Spades,
+    // a hand played without a high suit
+    NoTrump,
     _None,
 }
 
@@ -24,6 +28,7 @@ impl Suit {
             Suit::Diamonds => '♦',
             Suit::Clubs => '♣',
             Suit::Spades => '♠',
+            Suit::NoTrump => 'N',
             _ => '_',
         }
     }
@@ -34,6 +39,7 @@ impl Suit {
             Suit::Diamonds => "Diamonds".to_string(),
             Suit::Clubs => "Clubs".to_string(),
             Suit::Spades => "Spades".to_string(),
+            Suit::NoTrump => "No Trump".to_string(),
             _ => "None".to_string(),
         }
     }
@@ -46,54 +52,77 @@ impl Suit {
     }
 }
 
+// the faces in a standard euchre deck
+pub static EUCHRE_FACES: &[Face] = &[
+    Face::Nine,
+    Face::Ten,
+    Face::Jack,
+    Face::Queen,
+    Face::King,
+    Face::Ace,
+];
+
 // card face repr
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Face {
+    Seven,
+    Eight,
     Nine,
     Ten,
     Jack,
     Queen,
     King,
     Ace,
+    // only ever paired with no suit, see Card::joker
+    Joker,
 }
 
 impl Face {
     pub fn get_symbol(self) -> char {
         match self {
+            Face::Seven => '7',
+            Face::Eight => '8',
             Face::Nine => '9',
             Face::Ten => 'T',
             Face::Jack => 'J',
             Face::Queen => 'Q',
             Face::King => 'K',
             Face::Ace => 'A',
+            Face::Joker => '★',
         }
     }
 
     pub fn get_name(self) -> String {
         match self {
+            Face::Seven => "Seven".to_string(),
+            Face::Eight => "Eight".to_string(),
             Face::Nine => "Nine".to_string(),
             Face::Ten => "Ten".to_string(),
             Face::Jack => "Jack".to_string(),
             Face::Queen => "Queen".to_string(),
             Face::King => "King".to_string(),
             Face::Ace => "Ace".to_string(),
+            Face::Joker => "Joker".to_string(),
         }
     }
 
     pub fn get_rank(self) -> u8 {
         match self {
+            Face::Seven => 7,
+            Face::Eight => 8,
             Face::Nine => 9,
             Face::Ten => 10,
             Face::Jack => 11,
             Face::Queen => 12,
             Face::King => 13,
             Face::Ace => 14,
+            Face::Joker => 15,
         }
     }
 }
 
 // card repr
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Card {
     pub face: Face,
     pub suit: Suit,
@@ -104,7 +133,15 @@ impl Card {
         Card { face, suit }
     }
 
+    // the joker belongs to no suit until a high suit is called
+    pub fn joker() -> Self {
+        Card::new(Face::Joker, Suit::_None)
+    }
+
     pub fn get_name(self) -> String {
+        if self.is_joker() {
+            return self.face.get_name();
+        }
         let suit_symbol = self.suit.get_symbol();
         let face_symbol = self.face.get_symbol();
         face_symbol.to_string() + " of " + &suit_symbol.to_string()
@@ -127,29 +164,53 @@ impl Card {
     pub fn get_rank(self, high_suit: Suit, lead_suit: Suit) -> u8 {
         let rank = self.face.get_rank();
 
+        // card is the joker, the best bower
+        if self.is_joker() {
+            return rank + 17; // 32
+        }
         // card is left bower
         if self.is_left_bower(high_suit) {
-            return rank + 15; // 26
+            return rank + 19; // 30
         }
         match self.suit {
             s if s == high_suit => {
                 match self.face {
-                    Face::Nine | Face::Ten => {
-                        rank + 12 // 21, 22
+                    Face::Seven | Face::Eight | Face::Nine | Face::Ten => {
+                        rank + 16 // 23, 24, 25, 26
                     }
                     Face::Queen | Face::King | Face::Ace => {
-                        rank + 11 // 23, 24, 25
+                        rank + 15 // 27, 28, 29
                     }
-                    Face::Jack => rank + 16, // 27 (right bower)
+                    Face::Jack => rank + 20, // 31 (right bower)
+                    Face::Joker => unreachable!("the joker has no suit"),
                 }
             }
-            s if s == lead_suit => rank + 6, // 15, 16, 17, 18, 19, 20
-            _ => rank,                       // 9, 10, 11, 12, 13, 14
+            s if s == lead_suit => rank + 8, // 15, 16, 17, 18, 19, 20, 21, 22
+            _ => rank,                       // 7, 8, 9, 10, 11, 12, 13, 14
+        }
+    }
+
+    /// Return the suit the card belongs to when the given suit is high
+    ///
+    /// The left bower takes on the high suit, all other cards keep their own.
+    pub fn get_effective_suit(self, high_suit: Suit) -> Suit {
+        if self.is_left_bower(high_suit) || self.is_joker() {
+            high_suit
+        } else {
+            self.suit
         }
     }
 
+    pub fn is_joker(self) -> bool {
+        self.face == Face::Joker
+    }
+
+    pub fn is_black_jack(self) -> bool {
+        self.face == Face::Jack && self.get_color() == SuitColor::Black
+    }
+
     pub fn is_left_bower(self, high_suit: Suit) -> bool {
-        high_suit != Suit::_None
+        SUIT_VARIANTS.contains(&high_suit)
             && self.face == Face::Jack
             && self.get_color() == high_suit.get_color()
             && self.suit != high_suit
@@ -162,26 +223,24 @@ pub struct Deck {
 }
 
 impl Deck {
-    pub fn new() -> Self {
+    // create a shuffled deck of the given faces in every suit, plus the joker if wanted, the same
+    // rng state always gives the same order
+    pub fn new<R: Rng + ?Sized>(faces: &[Face], with_joker: bool, rng: &mut R) -> Self {
         let mut cards = Vec::new();
-        for &suit in &[Suit::Hearts, Suit::Diamonds, Suit::Clubs, Suit::Spades] {
-            for &face in &[
-                Face::Nine,
-                Face::Ten,
-                Face::Jack,
-                Face::Queen,
-                Face::King,
-                Face::Ace,
-            ] {
+        for &suit in SUIT_VARIANTS {
+            for &face in faces {
                 cards.push(Card::new(face, suit));
             }
         }
-        cards.shuffle(&mut rand::thread_rng());
+        if with_joker {
+            cards.push(Card::joker());
+        }
+        cards.shuffle(rng);
         Deck { cards }
     }
 
-    pub fn shuffle(&mut self) {
-        self.cards.shuffle(&mut rand::thread_rng());
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
     }
 
     pub fn deal(&mut self, num_cards: usize) -> Vec<Card> {
@@ -198,26 +257,33 @@ mod tests {
         let high_suit = Suit::Spades;
         let lead_suit = Suit::Diamonds;
 
-        assert!(Card::new(Face::Nine, Suit::Spades).get_rank(high_suit, lead_suit) == 21);
-        assert!(Card::new(Face::Ten, Suit::Spades).get_rank(high_suit, lead_suit) == 22);
-        assert!(Card::new(Face::Jack, Suit::Spades).get_rank(high_suit, lead_suit) == 27);
-        assert!(Card::new(Face::Queen, Suit::Spades).get_rank(high_suit, lead_suit) == 23);
-        assert!(Card::new(Face::King, Suit::Spades).get_rank(high_suit, lead_suit) == 24);
-        assert!(Card::new(Face::Ace, Suit::Spades).get_rank(high_suit, lead_suit) == 25);
+        assert!(Card::new(Face::Nine, Suit::Spades).get_rank(high_suit, lead_suit) == 25);
+        assert!(Card::new(Face::Ten, Suit::Spades).get_rank(high_suit, lead_suit) == 26);
+        assert!(Card::new(Face::Jack, Suit::Spades).get_rank(high_suit, lead_suit) == 31);
+        assert!(Card::new(Face::Queen, Suit::Spades).get_rank(high_suit, lead_suit) == 27);
+        assert!(Card::new(Face::King, Suit::Spades).get_rank(high_suit, lead_suit) == 28);
+        assert!(Card::new(Face::Ace, Suit::Spades).get_rank(high_suit, lead_suit) == 29);
 
         assert!(Card::new(Face::Nine, Suit::Clubs).get_rank(high_suit, lead_suit) == 9);
         assert!(Card::new(Face::Ten, Suit::Clubs).get_rank(high_suit, lead_suit) == 10);
-        assert!(Card::new(Face::Jack, Suit::Clubs).get_rank(high_suit, lead_suit) == 26);
+        assert!(Card::new(Face::Jack, Suit::Clubs).get_rank(high_suit, lead_suit) == 30);
         assert!(Card::new(Face::Queen, Suit::Clubs).get_rank(high_suit, lead_suit) == 12);
         assert!(Card::new(Face::King, Suit::Clubs).get_rank(high_suit, lead_suit) == 13);
         assert!(Card::new(Face::Ace, Suit::Clubs).get_rank(high_suit, lead_suit) == 14);
 
-        assert!(Card::new(Face::Nine, Suit::Diamonds).get_rank(high_suit, lead_suit) == 15);
-        assert!(Card::new(Face::Ten, Suit::Diamonds).get_rank(high_suit, lead_suit) == 16);
-        assert!(Card::new(Face::Jack, Suit::Diamonds).get_rank(high_suit, lead_suit) == 17);
-        assert!(Card::new(Face::Queen, Suit::Diamonds).get_rank(high_suit, lead_suit) == 18);
-        assert!(Card::new(Face::King, Suit::Diamonds).get_rank(high_suit, lead_suit) == 19);
-        assert!(Card::new(Face::Ace, Suit::Diamonds).get_rank(high_suit, lead_suit) == 20);
+        assert!(Card::new(Face::Nine, Suit::Diamonds).get_rank(high_suit, lead_suit) == 17);
+        assert!(Card::new(Face::Ten, Suit::Diamonds).get_rank(high_suit, lead_suit) == 18);
+        assert!(Card::new(Face::Jack, Suit::Diamonds).get_rank(high_suit, lead_suit) == 19);
+        assert!(Card::new(Face::Queen, Suit::Diamonds).get_rank(high_suit, lead_suit) == 20);
+        assert!(Card::new(Face::King, Suit::Diamonds).get_rank(high_suit, lead_suit) == 21);
+        assert!(Card::new(Face::Ace, Suit::Diamonds).get_rank(high_suit, lead_suit) == 22);
+
+        assert!(Card::new(Face::Eight, Suit::Spades).get_rank(high_suit, lead_suit) == 24);
+        assert!(Card::new(Face::Eight, Suit::Diamonds).get_rank(high_suit, lead_suit) == 16);
+        assert!(Card::new(Face::Eight, Suit::Clubs).get_rank(high_suit, lead_suit) == 8);
+        assert!(Card::new(Face::Seven, Suit::Spades).get_rank(high_suit, lead_suit) == 23);
+        assert!(Card::new(Face::Seven, Suit::Diamonds).get_rank(high_suit, lead_suit) == 15);
+        assert!(Card::new(Face::Seven, Suit::Clubs).get_rank(high_suit, lead_suit) == 7);
 
         assert!(Card::new(Face::Nine, Suit::Hearts).get_rank(high_suit, lead_suit) == 9);
         assert!(Card::new(Face::Ten, Suit::Hearts).get_rank(high_suit, lead_suit) == 10);
@@ -227,6 +293,24 @@ mod tests {
         assert!(Card::new(Face::Ace, Suit::Hearts).get_rank(high_suit, lead_suit) == 14);
     }
 
+    #[test]
+    fn test_card_rank_no_trump() {
+        // without trump the jacks rank in their own suit
+        let high_suit = Suit::NoTrump;
+        let lead_suit = Suit::Clubs;
+
+        assert!(Card::new(Face::Jack, Suit::Clubs).get_rank(high_suit, lead_suit) == 19);
+        assert!(Card::new(Face::Jack, Suit::Spades).get_rank(high_suit, lead_suit) == 11);
+        assert!(Card::new(Face::Ace, Suit::Clubs).get_rank(high_suit, lead_suit) == 22);
+    }
+
+    #[test]
+    fn test_joker_rank() {
+        // the joker tops the right bower whatever is high or led
+        assert!(Card::joker().get_rank(Suit::Spades, Suit::Diamonds) == 32);
+        assert!(Card::joker().get_rank(Suit::NoTrump, Suit::Clubs) == 32);
+    }
+
     #[test]
     fn test_card_rank_nones() {
         let high_suit = Suit::_None;
@@ -260,4 +344,58 @@ mod tests {
         assert!(Card::new(Face::King, Suit::Hearts).get_rank(high_suit, lead_suit) == 13);
         assert!(Card::new(Face::Ace, Suit::Hearts).get_rank(high_suit, lead_suit) == 14);
     }
+
+    #[test]
+    fn test_deck_seeded() {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        let deck = Deck::new(EUCHRE_FACES, false, &mut ChaCha8Rng::seed_from_u64(42));
+        assert!(deck.cards.len() == 24);
+        assert!(
+            deck.cards == Deck::new(EUCHRE_FACES, false, &mut ChaCha8Rng::seed_from_u64(42)).cards
+        );
+        assert!(
+            deck.cards != Deck::new(EUCHRE_FACES, false, &mut ChaCha8Rng::seed_from_u64(43)).cards
+        );
+
+        let deck = Deck::new(EUCHRE_FACES, true, &mut ChaCha8Rng::seed_from_u64(42));
+        assert!(deck.cards.len() == 25);
+        assert!(deck.cards.iter().filter(|card| card.is_joker()).count() == 1);
+
+        let faces = [&[Face::Eight], EUCHRE_FACES].concat();
+        assert!(
+            Deck::new(&faces, false, &mut ChaCha8Rng::seed_from_u64(42))
+                .cards
+                .len()
+                == 28
+        );
+
+        let faces = [&[Face::Seven, Face::Eight], EUCHRE_FACES].concat();
+        assert!(
+            Deck::new(&faces, false, &mut ChaCha8Rng::seed_from_u64(42))
+                .cards
+                .len()
+                == 32
+        );
+    }
+
+    #[test]
+    fn test_card_effective_suit() {
+        let high_suit = Suit::Hearts;
+
+        assert!(Card::new(Face::Jack, Suit::Hearts).get_effective_suit(high_suit) == Suit::Hearts);
+        assert!(
+            Card::new(Face::Jack, Suit::Diamonds).get_effective_suit(high_suit) == Suit::Hearts
+        );
+        assert!(Card::new(Face::Jack, Suit::Clubs).get_effective_suit(high_suit) == Suit::Clubs);
+        assert!(
+            Card::new(Face::Ace, Suit::Diamonds).get_effective_suit(high_suit) == Suit::Diamonds
+        );
+        assert!(
+            Card::new(Face::Jack, Suit::Diamonds).get_effective_suit(Suit::_None) == Suit::Diamonds
+        );
+        assert!(Card::joker().get_effective_suit(high_suit) == Suit::Hearts);
+        assert!(Card::joker().get_effective_suit(Suit::Spades) == Suit::Spades);
+    }
 }
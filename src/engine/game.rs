@@ -1,37 +1,48 @@
 use crate::engine::{
-    card::Deck,
+    action::{GameAction, GameEvent, RuleError},
+    card::{Card, Deck, Suit, SUIT_VARIANTS},
     player::{Player, PlayerType},
-    table::Seat,
+    rules::{AllPassRule, GameMode, RenegeRule, RuleSet, ThrowInRule},
+    table::{Bid, Seat, Table},
     team::Team,
     PlayerMap,
 };
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use std::{
     collections::HashSet,
     fmt::{Display, Formatter},
 };
 
-// const WINNING_SCORE: u8 = 10;
+// extra point for making a no trump call
+const NO_TRUMP_BONUS: u8 = 1;
 
-#[derive(Default)]
+// chance a bot notices and calls out a renege after each trick
+const RENEGE_CALL_CHANCE: f64 = 0.3;
+// chance a bot tries to get away with a renege when it can't follow suit for free
+const BOT_RENEGE_CHANCE: f64 = 0.05;
+// the seeded rng stream bot decisions draw from, clear of the streams the hands are shuffled from
+const BOT_RNG_STREAM: u64 = u64::MAX;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum GameState {
     #[default]
     PickingDealer,
     DealingHand,
+    CallingMisdeal,
+    SwappingFarmersHand,
     CallingPickup,
+    DealerDiscarding,
     CallingHighSuit,
+    Bidding,
+    NamingTrump,
+    CallingAce,
+    PassingToLoner,
+    LonerDiscarding,
+    DefendingAlone,
     PlayingHand,
-}
-
-impl GameState {
-    pub fn next(&self) -> Self {
-        match self {
-            GameState::PickingDealer => GameState::DealingHand,
-            GameState::DealingHand => GameState::CallingPickup,
-            GameState::CallingPickup => GameState::CallingHighSuit,
-            GameState::CallingHighSuit => GameState::PlayingHand,
-            GameState::PlayingHand => GameState::PickingDealer,
-        }
-    }
+    ScoringHand,
+    GameOver,
 }
 
 impl Display for GameState {
@@ -39,9 +50,20 @@ impl Display for GameState {
         match self {
             GameState::PickingDealer => write!(f, "Picking Dealer"),
             GameState::DealingHand => write!(f, "Dealing Hand"),
+            GameState::CallingMisdeal => write!(f, "Calling Misdeal"),
+            GameState::SwappingFarmersHand => write!(f, "Swapping Farmer's Hand"),
             GameState::CallingPickup => write!(f, "Calling Pickup"),
+            GameState::DealerDiscarding => write!(f, "Dealer Discarding"),
             GameState::CallingHighSuit => write!(f, "Calling High Suit"),
+            GameState::Bidding => write!(f, "Bidding"),
+            GameState::NamingTrump => write!(f, "Naming Trump"),
+            GameState::CallingAce => write!(f, "Calling Ace"),
+            GameState::PassingToLoner => write!(f, "Passing To Loner"),
+            GameState::LonerDiscarding => write!(f, "Loner Discarding"),
+            GameState::DefendingAlone => write!(f, "Defending Alone"),
             GameState::PlayingHand => write!(f, "Playing Hand"),
+            GameState::ScoringHand => write!(f, "Scoring Hand"),
+            GameState::GameOver => write!(f, "Game Over"),
         }
     }
 }
@@ -49,53 +71,97 @@ impl Display for GameState {
 // Define the game state
 pub struct Game {
     pub state: GameState,
-    // TODO: should teams be in an array or set to make for easy iteration?
-    pub user_team: Team,
-    pub opp_team: Team,
+    pub rules: RuleSet,
+    pub seed: u64,
+    // bot decisions draw from their own rng, so they never change what is dealt
+    bot_rng: ChaCha8Rng,
+    // the user's team comes first
+    pub teams: Vec<Team>,
+    pub seats: Vec<Seat>,
     pub players: PlayerMap,
     pub deck: Deck,
+    pub table: Table,
     pub current_player_seat: Seat,
-    pub dealer_seat: Seat,
-    pub leader_seat: Seat,
     pub hand_num: u8,
+    pub turned_down_suit: Suit,
+    pub high_suit: Suit,
+    pub messages: Vec<String>,
+    // events since the last action or step, handed back to the caller
+    events: Vec<GameEvent>,
 }
 
 impl Game {
-    // Initialize the game state with a specified number of CPU players
+    // Initialize the game state with the user and CPU players seated around the table
+    //
+    // player names start with the user and go left around the table, team names start with the
+    // user's team and are only used when the mode has partners
     pub fn new(
-        user_name: String,
-        partner_name: String,
-        opp1_name: String,
-        opp2_name: String,
-        user_team_name: String,
-        opp_team_name: String,
+        player_names: Vec<String>,
+        team_names: Vec<String>,
+        rules: RuleSet,
+        seed: u64,
     ) -> Self {
-        // create and shuffle the deck
-        let deck = Deck::new();
-
-        // create teams
-        let user_team = Team::new(user_team_name, HashSet::from([Seat::Bottom, Seat::Top]));
-        let opp_team = Team::new(opp_team_name, HashSet::from([Seat::Left, Seat::Right]));
+        // create and shuffle the deck for picking the first dealer
+        let deck = seeded_deck(&rules, seed, 0);
+        let mut bot_rng = ChaCha8Rng::seed_from_u64(seed);
+        bot_rng.set_stream(BOT_RNG_STREAM);
 
         // create player/seat map
-        let players = PlayerMap::from([
-            (Seat::Bottom, Player::new(user_name, PlayerType::User)),
-            (Seat::Top, Player::new(partner_name, PlayerType::Bot)),
-            (Seat::Left, Player::new(opp1_name, PlayerType::Bot)),
-            (Seat::Right, Player::new(opp2_name, PlayerType::Bot)),
-        ]);
+        let seats = rules.mode.seats();
+        let players: PlayerMap = seats
+            .iter()
+            .zip(player_names)
+            .enumerate()
+            .map(|(idx, (seat, name))| {
+                let player_type = if idx == 0 {
+                    PlayerType::User
+                } else {
+                    PlayerType::Bot
+                };
+                (seat.clone(), Player::new(name, player_type))
+            })
+            .collect();
+
+        // create teams, partners sit across from each other so the teams alternate around the table
+        let teams = if rules.mode.has_partners() {
+            let num_teams = seats.len() / 2;
+            let mut team_names = team_names.into_iter();
+            (0..num_teams)
+                .map(|idx| {
+                    let name = team_names
+                        .next()
+                        .unwrap_or_else(|| format!("Team {}", idx + 1));
+                    let team_seats = seats.iter().skip(idx).step_by(num_teams).cloned();
+                    Team::new(name, HashSet::from_iter(team_seats))
+                })
+                .collect()
+        } else {
+            seats
+                .iter()
+                .map(|seat| {
+                    let name = players.get(seat).expect("player not found").name.clone();
+                    Team::new(name, HashSet::from([seat.clone()]))
+                })
+                .collect()
+        };
 
         // using ..Default::default() here will not work. Causes stack overflow. Idk why
         Game {
             state: GameState::default(),
-            user_team,
-            opp_team,
+            rules,
+            seed,
+            bot_rng,
+            teams,
+            seats,
             players,
             deck,
+            table: Table::default(),
             current_player_seat: Seat::default(),
-            dealer_seat: Seat::default(),
-            leader_seat: Seat::default(),
             hand_num: 0,
+            turned_down_suit: Suit::_None,
+            high_suit: Suit::_None,
+            messages: vec![],
+            events: vec![],
         }
     }
 
@@ -115,55 +181,2644 @@ impl Game {
         self.players.get(&seat).expect("player not found")
     }
 
+    pub fn get_team_by_seat(&self, seat: Seat) -> &Team {
+        self.teams
+            .iter()
+            .find(|team| team.seats.contains(&seat))
+            .expect("team not found")
+    }
+
+    fn get_team_by_seat_mut(&mut self, seat: Seat) -> &mut Team {
+        self.teams
+            .iter_mut()
+            .find(|team| team.seats.contains(&seat))
+            .expect("team not found")
+    }
+
+    // every team playing against the player in the given seat
+    fn get_opposing_teams_mut(&mut self, seat: Seat) -> Vec<&mut Team> {
+        self.teams
+            .iter_mut()
+            .filter(|team| !team.seats.contains(&seat))
+            .collect()
+    }
+
+    pub fn maker_team(&self) -> Option<&Team> {
+        self.table
+            .maker_seat
+            .clone()
+            .map(|seat| self.get_team_by_seat(seat))
+    }
+
+    pub fn latest_message(&self) -> &str {
+        self.messages.last().map(String::as_str).unwrap_or_default()
+    }
+
+    // whether the game is waiting on a decision from the current player, rather than on its next
+    // automatic step
+    pub fn is_awaiting_action(&self) -> bool {
+        matches!(
+            self.state,
+            GameState::CallingMisdeal
+                | GameState::SwappingFarmersHand
+                | GameState::CallingPickup
+                | GameState::DealerDiscarding
+                | GameState::CallingHighSuit
+                | GameState::Bidding
+                | GameState::NamingTrump
+                | GameState::CallingAce
+                | GameState::PassingToLoner
+                | GameState::LonerDiscarding
+                | GameState::DefendingAlone
+                | GameState::PlayingHand
+        ) && !self.is_trick_complete()
+    }
+
+    // whether the game is waiting on a decision from the user
+    pub fn is_awaiting_user(&self) -> bool {
+        self.current_player().player_type == PlayerType::User
+            && self.is_awaiting_action()
+            && self.forced_card_idx().is_none()
+    }
+
+    // the partner of the player in the given seat, if they have one
+    pub fn get_partner_seat(&self, seat: Seat) -> Option<Seat> {
+        self.get_team_by_seat(seat.clone())
+            .seats
+            .iter()
+            .find(|team_seat| **team_seat != seat)
+            .cloned()
+    }
+
+    // whether the maker may send their partner out to play the hand alone
+    pub fn can_go_alone(&self) -> bool {
+        self.rules.uses_loners()
+    }
+
+    // the partner the player in the given seat knows about, a called partner knows from the
+    // start but the maker only finds out when the called card is played
+    fn known_partner_seat(&self, seat: Seat) -> Option<Seat> {
+        if self.rules.mode != GameMode::CallAce {
+            return self.get_partner_seat(seat);
+        }
+        if self.table.partner_seat == Some(seat.clone()) {
+            self.table.maker_seat.clone()
+        } else if self.table.maker_seat == Some(seat) && self.table.partner_revealed {
+            self.table.partner_seat.clone()
+        } else {
+            None
+        }
+    }
+
+    // the seats playing for the makers this hand, including a called partner
+    fn maker_seats(&self) -> Vec<Seat> {
+        let Some(maker_seat) = self.table.maker_seat.clone() else {
+            return vec![];
+        };
+        let mut seats: Vec<Seat> = self
+            .get_team_by_seat(maker_seat)
+            .seats
+            .iter()
+            .cloned()
+            .collect();
+        if let Some(partner_seat) = self.table.partner_seat.clone() {
+            if !seats.contains(&partner_seat) {
+                seats.push(partner_seat);
+            }
+        }
+        seats
+    }
+
+    // the tricks taken so far by everyone playing for the makers
+    fn maker_tricks(&self) -> u8 {
+        let maker_seats = self.maker_seats();
+        self.teams
+            .iter()
+            .filter(|team| team.seats.iter().any(|seat| maker_seats.contains(seat)))
+            .map(|team| team.hand_score)
+            .sum()
+    }
+
+    pub fn is_sitting_out(&self, seat: &Seat) -> bool {
+        self.table.sitting_out_seats.contains(seat)
+    }
+
+    // the next seat to the left around however many seats are in play
+    fn next_seat(&self, seat: Seat) -> Seat {
+        let idx = self
+            .seats
+            .iter()
+            .position(|ring_seat| *ring_seat == seat)
+            .expect("seat not in play");
+        self.seats[(idx + 1) % self.seats.len()].clone()
+    }
+
+    // the next seat to the left that is not sitting out the hand
+    fn next_active_seat(&self, seat: Seat) -> Seat {
+        let mut next_seat = self.next_seat(seat);
+        while self.is_sitting_out(&next_seat) {
+            next_seat = self.next_seat(next_seat);
+        }
+        next_seat
+    }
+
     fn next_turn(&mut self) {
-        self.current_player_seat = self.current_player_seat.next();
+        self.current_player_seat = self.next_active_seat(self.current_player_seat.clone());
+    }
+
+    fn log(&mut self, message: String) {
+        self.messages.push(message.clone());
+        self.events.push(GameEvent::Message(message));
+    }
+
+    // order the upcard up to the dealer, making its suit high
+    fn order_up(&mut self, alone: bool) -> Result<(), RuleError> {
+        if alone && !self.can_go_alone() {
+            return Err(RuleError::LonersNotAllowed);
+        }
+        let Some(upcard) = self.table.upcard.take() else {
+            return Err(RuleError::NotAllowedNow);
+        };
+        let caller = self.current_player().name.clone();
+        if self.current_player_seat == self.table.dealer_seat {
+            self.log(format!("{} picked up the {}", caller, upcard.get_name()));
+        } else {
+            self.log(format!("{} ordered up the {}", caller, upcard.get_name()));
+        }
+        self.high_suit = upcard.suit;
+        self.table.maker_seat = Some(self.current_player_seat.clone());
+        if alone {
+            self.go_alone();
+        }
+
+        if self.is_sitting_out(&self.table.dealer_seat.clone()) {
+            // dealer's partner is going alone, so the upcard stays out of play
+            self.table.bury(upcard);
+            self.finish_calling();
+        } else {
+            // dealer picks up the upcard and must discard
+            self.current_player_seat = self.table.dealer_seat.clone();
+            self.current_player_mut().pickup_card(upcard);
+            self.state = GameState::DealerDiscarding;
+        }
+        Ok(())
+    }
+
+    // play the hand without a partner
+    fn go_alone(&mut self) {
+        self.log(format!("{} is going alone", self.current_player().name));
+        self.table.alone_seat = Some(self.current_player_seat.clone());
+        // in railroad the partner stays in long enough to pass the loner a card
+        if self.rules.mode == GameMode::Railroad {
+            return;
+        }
+        if let Some(partner_seat) = self.get_partner_seat(self.current_player_seat.clone()) {
+            self.sit_out(partner_seat);
+        }
+    }
+
+    // the loner's partner, while they still owe the loner a card
+    fn passing_partner_seat(&self) -> Option<Seat> {
+        if self.rules.mode != GameMode::Railroad {
+            return None;
+        }
+        self.table
+            .alone_seat
+            .clone()
+            .and_then(|alone_seat| self.get_partner_seat(alone_seat))
+            .filter(|partner_seat| !self.is_sitting_out(partner_seat))
+    }
+
+    // pass a card from the loner's partner to the loner, who then discards
+    fn pass_to_loner(&mut self, card_idx: usize) -> Result<(), RuleError> {
+        let Some(alone_seat) = self.table.alone_seat.clone() else {
+            return Err(RuleError::NotAllowedNow);
+        };
+        if card_idx >= self.current_player().hand.len() {
+            return Err(RuleError::NoSuchCard(card_idx));
+        }
+        let card = self.current_player_mut().discard_card(card_idx);
+        self.log(format!(
+            "{} passed a card to {}",
+            self.current_player().name,
+            self.get_player_in_seat(alone_seat.clone()).name
+        ));
+        self.sit_out(self.current_player_seat.clone());
+        self.current_player_seat = alone_seat;
+        self.current_player_mut().pickup_card(card);
+        self.state = GameState::LonerDiscarding;
+        Ok(())
+    }
+
+    // fold a player's hand for the rest of the hand
+    fn sit_out(&mut self, seat: Seat) {
+        let player = self.players.get_mut(&seat).expect("player not found");
+        for card in player.hand.drain(..) {
+            self.table.bury(card);
+        }
+        self.table.sitting_out_seats.push(seat);
+    }
+
+    // give the defenders a chance to answer a loner before play starts
+    fn finish_calling(&mut self) {
+        if self.rules.mode == GameMode::CallAce
+            && self.table.partner_suit.is_none()
+            && self.table.alone_seat.is_none()
+        {
+            if let Some(maker_seat) = self.table.maker_seat.clone() {
+                self.current_player_seat = maker_seat;
+                self.state = GameState::CallingAce;
+                return;
+            }
+        }
+        if let Some(partner_seat) = self.passing_partner_seat() {
+            self.current_player_seat = partner_seat;
+            self.state = GameState::PassingToLoner;
+            return;
+        }
+        match self.table.alone_seat.clone() {
+            // with three teams there is no single defender to answer the loner
+            Some(alone_seat) if self.rules.uses_defend_alone() && self.teams.len() == 2 => {
+                self.current_player_seat = self.next_seat(alone_seat);
+                self.state = GameState::DefendingAlone;
+            }
+            _ => self.start_play(),
+        }
+    }
+
+    // defend alone against a loner, or leave it to the other defender
+    fn defend_alone(&mut self, alone: bool) {
+        let defender_seat = self.current_player_seat.clone();
+        if alone {
+            self.log(format!("{} is defending alone", self.current_player().name));
+            self.table.defender_alone_seat = Some(defender_seat.clone());
+            if let Some(partner_seat) = self.get_partner_seat(defender_seat) {
+                self.sit_out(partner_seat);
+            }
+            self.start_play();
+            return;
+        }
+        self.log(format!(
+            "{} will not defend alone",
+            self.current_player().name
+        ));
+        let first_defender_seat = self
+            .table
+            .alone_seat
+            .clone()
+            .map(|seat| self.next_seat(seat));
+        match self.get_partner_seat(defender_seat.clone()) {
+            Some(partner_seat) if first_defender_seat == Some(defender_seat) => {
+                self.current_player_seat = partner_seat;
+            }
+            _ => self.start_play(),
+        }
+    }
+
+    // find the first player from the given seat through the dealer whose hand matches
+    fn find_hand_from(&self, mut seat: Seat, matches: fn(&Player) -> bool) -> Option<Seat> {
+        loop {
+            if matches(self.get_player_in_seat(seat.clone())) {
+                return Some(seat);
+            }
+            if seat == self.table.dealer_seat {
+                return None;
+            }
+            seat = self.next_seat(seat);
+        }
+    }
+
+    // offer a misdeal to the next player holding no face cards or aces
+    fn check_ace_no_face(&mut self, first_seat: Seat) {
+        let misdeal_seat = self
+            .find_hand_from(first_seat, Player::has_ace_no_face)
+            .filter(|_| self.rules.ace_no_face);
+        match misdeal_seat {
+            Some(seat) => {
+                self.current_player_seat = seat;
+                self.state = GameState::CallingMisdeal;
+            }
+            None => self.check_farmers_hand(self.next_seat(self.table.dealer_seat.clone())),
+        }
+    }
+
+    // offer the kitty to the next player holding a farmer's hand
+    fn check_farmers_hand(&mut self, first_seat: Seat) {
+        let farmer_seat = self
+            .find_hand_from(first_seat, Player::has_farmers_hand)
+            .filter(|_| self.rules.uses_farmers_hand() && !self.table.kitty.is_empty());
+        match farmer_seat {
+            Some(seat) => {
+                self.current_player_seat = seat;
+                self.state = GameState::SwappingFarmersHand;
+            }
+            None => self.start_calling(),
+        }
+    }
+
+    // start calling for the upcard, or bidding in pepper, from the left of the dealer
+    fn start_calling(&mut self) {
+        if self.table.upcard.is_some_and(Card::is_joker) {
+            // the dealer takes a turned up joker and names trump, alone if they like
+            self.current_player_seat = self.table.dealer_seat.clone();
+            self.table.maker_seat = Some(self.current_player_seat.clone());
+            self.log(format!(
+                "{} takes the joker and names trump",
+                self.current_player().name
+            ));
+            self.state = GameState::NamingTrump;
+            return;
+        }
+        self.current_player_seat = self.next_seat(self.table.dealer_seat.clone());
+        self.state = match self.rules.mode {
+            GameMode::Pepper => GameState::Bidding,
+            _ => GameState::CallingPickup,
+        };
+    }
+
+    // throw in a hand with no face cards or aces and have the dealer deal again
+    fn call_misdeal(&mut self, misdeal: bool) -> Result<(), RuleError> {
+        if !self.current_player().has_ace_no_face() {
+            return Err(RuleError::NotAllowedNow);
+        }
+        if !misdeal {
+            self.log(format!(
+                "{} kept a hand with no face cards or aces",
+                self.current_player().name
+            ));
+            if self.current_player_seat == self.table.dealer_seat {
+                self.check_farmers_hand(self.next_seat(self.table.dealer_seat.clone()));
+            } else {
+                self.check_ace_no_face(self.next_seat(self.current_player_seat.clone()));
+            }
+            return Ok(());
+        }
+        self.log(format!(
+            "{} called a misdeal with no face cards or aces",
+            self.current_player().name
+        ));
+        self.reset_hand();
+        self.current_player_seat = self.next_seat(self.table.dealer_seat.clone());
+        self.state = GameState::DealingHand;
+        Ok(())
+    }
+
+    // swap the nines and tens of a farmer's hand for the kitty
+    fn swap_farmers_hand(&mut self, swap: bool) -> Result<(), RuleError> {
+        if !self.current_player().has_farmers_hand() {
+            return Err(RuleError::NotAllowedNow);
+        }
+        if !swap {
+            self.log(format!(
+                "{} kept their nines and tens",
+                self.current_player().name
+            ));
+            if self.current_player_seat == self.table.dealer_seat {
+                self.start_calling();
+            } else {
+                self.check_farmers_hand(self.next_seat(self.current_player_seat.clone()));
+            }
+            return Ok(());
+        }
+        let num_cards = self.table.kitty.len();
+        let cards = self.current_player_mut().take_farmers_cards(num_cards);
+        self.log(format!(
+            "{} swapped {} nines and tens with the kitty",
+            self.current_player().name,
+            cards.len()
+        ));
+        let kitty = self.table.swap_kitty(cards);
+        self.current_player_mut().hand.extend(kitty);
+        self.start_calling();
+        Ok(())
+    }
+
+    // lead the first trick from the left of the dealer
+    fn start_play(&mut self) {
+        for (seat, player) in self.players.iter() {
+            self.table
+                .starting_hands
+                .insert(seat.clone(), player.hand.clone());
+        }
+        self.table.leader_seat = self.next_active_seat(self.table.dealer_seat.clone());
+        self.current_player_seat = self.table.leader_seat.clone();
+        self.state = GameState::PlayingHand;
+    }
+
+    // whether the current player must call a suit or bid rather than pass
+    pub fn is_dealer_stuck(&self) -> bool {
+        (matches!(self.state, GameState::CallingHighSuit)
+            || matches!(self.state, GameState::Bidding) && self.table.bid.is_none())
+            && self.current_player_seat == self.table.dealer_seat
+            && self.rules.all_pass == AllPassRule::StickTheDealer
+    }
+
+    // pass on ordering up the upcard or calling a suit
+    fn pass(&mut self) -> Result<(), RuleError> {
+        if self.is_dealer_stuck() {
+            return Err(RuleError::DealerStuck);
+        }
+        self.log(format!("{} passed", self.current_player().name));
+        if self.current_player_seat == self.table.dealer_seat {
+            match self.state {
+                GameState::Bidding if self.table.bid.is_some() => {
+                    self.finish_bidding();
+                    return Ok(());
+                }
+                GameState::CallingPickup => {
+                    // everyone passed, turn down the upcard and call again
+                    if let Some(upcard) = self.table.turn_down() {
+                        self.log(format!("The {} is turned down", upcard.get_name()));
+                        self.turned_down_suit = upcard.suit;
+                    }
+                    self.state = GameState::CallingHighSuit;
+                }
+                _ => {
+                    // everyone passed again, throw in the hand and deal again
+                    self.log("Everyone passed, the deal moves on".to_string());
+                    self.reset_hand();
+                    self.state = GameState::PickingDealer;
+                    return Ok(());
+                }
+            }
+        }
+        self.next_turn();
+        Ok(())
+    }
+
+    // whether no trump may be called in place of a suit
+    pub fn can_call_no_trump(&self) -> bool {
+        self.rules.no_trump && matches!(self.state, GameState::CallingHighSuit)
+    }
+
+    // call a suit other than the turned down suit as high, or no trump if the rules allow it
+    fn call_suit(&mut self, suit: Suit, alone: bool) -> Result<(), RuleError> {
+        if suit == Suit::_None || suit == Suit::NoTrump && !self.can_call_no_trump() {
+            return Err(RuleError::SuitNotAllowed(suit));
+        }
+        if suit == self.turned_down_suit {
+            return Err(RuleError::TurnedDown(suit));
+        }
+        if alone && !self.can_go_alone() {
+            return Err(RuleError::LonersNotAllowed);
+        }
+        self.log(format!(
+            "{} called {}",
+            self.current_player().name,
+            suit.get_name()
+        ));
+        self.high_suit = suit;
+        self.table.maker_seat = Some(self.current_player_seat.clone());
+        if alone {
+            self.go_alone();
+        }
+        self.finish_calling();
+        Ok(())
+    }
+
+    // the lowest bid the current player may make, if any bid can top the high bid
+    pub fn min_bid(&self) -> Option<Bid> {
+        let num_tricks = self.rules.mode.hand_size() as u8;
+        match self.table.bid {
+            None => Some(Bid::Tricks(1)),
+            Some(Bid::Tricks(tricks)) if tricks < num_tricks => Some(Bid::Tricks(tricks + 1)),
+            Some(Bid::Tricks(_)) if self.can_go_alone() => Some(Bid::Pepper),
+            _ => None,
+        }
+    }
+
+    // whether the current player may make the given bid
+    pub fn can_bid(&self, bid: Bid) -> bool {
+        let in_range = match bid {
+            Bid::Tricks(tricks) => tricks as usize <= self.rules.mode.hand_size(),
+            Bid::Pepper => self.can_go_alone(),
+        };
+        in_range && self.min_bid().is_some_and(|min_bid| bid >= min_bid)
+    }
+
+    // bid to take at least some number of tricks, topping the high bid
+    fn bid(&mut self, bid: Bid) -> Result<(), RuleError> {
+        if !self.can_bid(bid) {
+            return Err(RuleError::BidNotAllowed(bid));
+        }
+        self.log(format!("{} bid {}", self.current_player().name, bid));
+        self.table.bid = Some(bid);
+        self.table.maker_seat = Some(self.current_player_seat.clone());
+        // nobody can top pepper
+        if self.current_player_seat == self.table.dealer_seat || bid == Bid::Pepper {
+            self.finish_bidding();
+        } else {
+            self.next_turn();
+        }
+        Ok(())
+    }
+
+    // hand the high bidder the choice of trump
+    fn finish_bidding(&mut self) {
+        let (Some(bid), Some(maker_seat)) = (self.table.bid, self.table.maker_seat.clone()) else {
+            return;
+        };
+        self.current_player_seat = maker_seat;
+        self.log(format!(
+            "{} won the bid at {}",
+            self.current_player().name,
+            bid
+        ));
+        if bid == Bid::Pepper {
+            self.go_alone();
+        }
+        self.state = GameState::NamingTrump;
+    }
+
+    // whether the high bidder may play the hand with no trump
+    pub fn can_name_no_trump(&self) -> bool {
+        self.table.bid.is_some()
+    }
+
+    // whether the player naming trump may also go alone, a bid winner already had that
+    // choice with a pepper bid, so only a dealer taking a turned up joker has it here
+    pub fn can_name_alone(&self) -> bool {
+        self.can_go_alone() && self.table.bid.is_none()
+    }
+
+    // name the high suit for the hand, or play it with no trump
+    fn name_trump(&mut self, suit: Suit, alone: bool) -> Result<(), RuleError> {
+        let no_trump = suit == Suit::NoTrump && self.can_name_no_trump();
+        if !SUIT_VARIANTS.contains(&suit) && !no_trump {
+            return Err(RuleError::SuitNotAllowed(suit));
+        }
+        if alone && !self.can_name_alone() {
+            return Err(RuleError::LonersNotAllowed);
+        }
+        self.log(format!(
+            "{} named {}",
+            self.current_player().name,
+            suit.get_name()
+        ));
+        self.high_suit = suit;
+        if alone {
+            self.go_alone();
+        }
+        match self.table.upcard.take() {
+            // the dealer picks up the turned up joker and must discard
+            Some(upcard) => {
+                self.current_player_mut().pickup_card(upcard);
+                self.state = GameState::DealerDiscarding;
+            }
+            None => self.finish_calling(),
+        }
+        Ok(())
+    }
+
+    // call an off suit, whoever holds its best card secretly partners the maker for the hand.
+    // when the maker holds it or it is out of play, the maker plays without a partner, though
+    // it is scored as an ordinary hand since they never declared alone
+    fn call_ace(&mut self, suit: Suit) -> Result<(), RuleError> {
+        if !SUIT_VARIANTS.contains(&suit) || suit == self.high_suit {
+            return Err(RuleError::SuitNotAllowed(suit));
+        }
+        let maker_seat = self.current_player_seat.clone();
+        let out_of_play = self
+            .table
+            .kitty
+            .iter()
+            .chain(self.table.buried.iter())
+            .chain(self.table.upcard.iter())
+            .map(|card| (None, *card));
+        let partner_play = self
+            .players
+            .iter()
+            .flat_map(|(seat, player)| player.hand.iter().map(move |card| (Some(seat), *card)))
+            .chain(out_of_play)
+            .filter(|(_, card)| card.get_effective_suit(self.high_suit) == suit)
+            .max_by_key(|(_, card)| card.get_rank(self.high_suit, suit))
+            .and_then(|(seat, card)| seat.map(|seat| (seat.clone(), card)))
+            .filter(|(seat, _)| *seat != maker_seat);
+        self.log(format!(
+            "{} called the best of {} for a partner",
+            self.current_player().name,
+            suit.get_name()
+        ));
+        self.table.partner_suit = Some(suit);
+        if let Some((partner_seat, partner_card)) = partner_play {
+            self.table.partner_seat = Some(partner_seat);
+            self.table.partner_card = Some(partner_card);
+        }
+        self.finish_calling();
+        Ok(())
+    }
+
+    // discard a card from the dealer's hand after picking up
+    fn discard(&mut self, card_idx: usize) -> Result<(), RuleError> {
+        if card_idx >= self.current_player().hand.len() {
+            return Err(RuleError::NoSuchCard(card_idx));
+        }
+        let card = self.current_player_mut().discard_card(card_idx);
+        self.table.bury(card);
+        self.log(format!("{} discarded a card", self.current_player().name));
+        self.finish_calling();
+        Ok(())
+    }
+
+    pub fn is_trick_complete(&self) -> bool {
+        self.table.trick.plays.len() == self.players.len() - self.table.sitting_out_seats.len()
+    }
+
+    // play a card from the current player's hand to the trick
+    fn play_card(&mut self, card_idx: usize) -> Result<(), RuleError> {
+        let lead_suit = self.table.trick.lead_suit(self.high_suit);
+        let player = self.current_player();
+        if card_idx >= player.hand.len() {
+            return Err(RuleError::NoSuchCard(card_idx));
+        }
+        // reneging is allowed when it can be called out, the table keeps the evidence
+        if self.rules.renege == RenegeRule::Prevent
+            && !player
+                .legal_card_idxs(lead_suit, self.high_suit)
+                .contains(&card_idx)
+        {
+            return Err(RuleError::MustFollow(lead_suit));
+        }
+
+        let card = self.current_player_mut().play_card(card_idx);
+        self.log(format!(
+            "{} played the {}",
+            self.current_player().name,
+            card.get_name()
+        ));
+        self.table.play(self.current_player_seat.clone(), card);
+        self.events.push(GameEvent::CardPlayed {
+            seat: self.current_player_seat.clone(),
+            card,
+        });
+        if self.table.partner_card == Some(card) && !self.table.partner_revealed {
+            self.table.partner_revealed = true;
+            let maker_name = self
+                .table
+                .maker_seat
+                .clone()
+                .map(|seat| self.get_player_in_seat(seat).name.clone())
+                .unwrap_or_default();
+            self.log(format!(
+                "{} is {}'s partner",
+                self.current_player().name,
+                maker_name
+            ));
+        }
+
+        if self.is_trick_complete() {
+            self.take_trick();
+        } else {
+            self.next_turn();
+        }
+        Ok(())
+    }
+
+    // the only card the current player may play, when it should be played automatically
+    pub fn forced_card_idx(&self) -> Option<usize> {
+        if !self.rules.auto_play || !matches!(self.state, GameState::PlayingHand) {
+            return None;
+        }
+        let legal_idxs = self
+            .current_player()
+            .legal_card_idxs(self.table.trick.lead_suit(self.high_suit), self.high_suit);
+        match legal_idxs[..] {
+            [card_idx] => Some(card_idx),
+            _ => None,
+        }
+    }
+
+    // the cards the current player has not seen, wherever they are held
+    fn unseen_cards(&self) -> Vec<Card> {
+        let mut cards: Vec<Card> = self
+            .players
+            .iter()
+            .filter(|(seat, _)| **seat != self.current_player_seat)
+            .flat_map(|(_, player)| player.hand.clone())
+            .collect();
+        cards.extend(self.table.kitty.clone());
+        cards.extend(self.table.buried.clone());
+        cards.extend(self.table.upcard);
+        cards
+    }
+
+    // whether the current player is on lead with only sure winners left
+    pub fn can_claim(&self) -> bool {
+        matches!(self.state, GameState::PlayingHand)
+            && self.table.trick.plays.is_empty()
+            && claim_holds(
+                &self.current_player().hand,
+                &self.unseen_cards(),
+                self.high_suit,
+            )
+    }
+
+    // claim the rest of the tricks for the current player's team
+    fn claim_tricks(&mut self) -> Result<(), RuleError> {
+        if !self.table.trick.plays.is_empty() {
+            return Err(RuleError::ClaimNotOnLead);
+        }
+        if !self.can_claim() {
+            return Err(RuleError::ClaimRejected);
+        }
+        let claimer_name = self.current_player().name.clone();
+        let num_tricks = self.current_player().hand.len();
+        self.get_team_by_seat_mut(self.current_player_seat.clone())
+            .hand_score += num_tricks as u8;
+        self.log(format!(
+            "{} claimed the last {} tricks",
+            claimer_name, num_tricks
+        ));
+        for player in self.players.values_mut() {
+            for card in player.hand.drain(..) {
+                self.table.bury(card);
+            }
+        }
+        self.state = GameState::ScoringHand;
+        Ok(())
+    }
+
+    // award the completed trick to its winner, who leads the next trick
+    fn take_trick(&mut self) {
+        let Some((winner_seat, _)) = self.table.trick.winning_play(self.high_suit).cloned() else {
+            return;
+        };
+        self.table.trick.winner_seat = Some(winner_seat.clone());
+        self.events.push(GameEvent::TrickTaken {
+            seat: winner_seat.clone(),
+        });
+        self.get_team_by_seat_mut(winner_seat.clone()).hand_score += 1;
+        self.log(format!(
+            "{} took the trick",
+            self.get_player_in_seat(winner_seat.clone()).name
+        ));
+        self.table.leader_seat = winner_seat.clone();
+        self.current_player_seat = winner_seat;
+    }
+
+    // whether the tricks left to play can no longer change the score for the hand
+    fn is_hand_decided(&self) -> bool {
+        if self.table.maker_seat.is_none() {
+            return false;
+        }
+        // every trick counts toward the score in pepper
+        if self.rules.mode == GameMode::Pepper {
+            return false;
+        }
+        hand_decided(
+            self.maker_tricks(),
+            self.current_player().hand.len() as u8,
+            self.rules.mode.hand_size() as u8,
+            self.table.alone_seat.is_some(),
+            self.table.defender_alone_seat.is_some(),
+        )
+    }
+
+    // stop playing a decided hand and score it as it stands
+    fn throw_in(&mut self) {
+        for player in self.players.values_mut() {
+            for card in player.hand.drain(..) {
+                self.table.bury(card);
+            }
+        }
+        self.table.thrown_in = true;
+        self.state = GameState::ScoringHand;
+    }
+
+    // call out a renege by the opposing team, ending the hand if there was one
+    fn call_renege(&mut self, caller_seat: Seat) {
+        let caller_name = self.get_player_in_seat(caller_seat.clone()).name.clone();
+        let caller_team_seats = self.get_team_by_seat(caller_seat).seats.clone();
+        let renege_seat = self
+            .table
+            .find_reneges(self.high_suit)
+            .into_iter()
+            .find(|seat| !caller_team_seats.contains(seat));
+        match renege_seat {
+            Some(renege_seat) => {
+                self.log(format!(
+                    "{} caught {} reneging",
+                    caller_name,
+                    self.get_player_in_seat(renege_seat.clone()).name
+                ));
+                self.table.renege_seat = Some(renege_seat);
+                self.state = GameState::ScoringHand;
+            }
+            None => self.log(format!(
+                "{} called a renege, but there was none",
+                caller_name
+            )),
+        }
+    }
+
+    // give each bot a chance to notice a renege by the opposing team
+    fn bots_call_renege(&mut self) {
+        if self.rules.renege != RenegeRule::CallOut {
+            return;
+        }
+        let reneges = self.table.find_reneges(self.high_suit);
+        let bot_seats: Vec<Seat> = self
+            .seats
+            .iter()
+            .filter(|seat| {
+                let player = self.get_player_in_seat((*seat).clone());
+                let team = self.get_team_by_seat((*seat).clone());
+                player.player_type == PlayerType::Bot
+                    && !self.is_sitting_out(seat)
+                    && reneges
+                        .iter()
+                        .any(|renege_seat| !team.seats.contains(renege_seat))
+            })
+            .cloned()
+            .collect();
+        for seat in bot_seats {
+            if self.bot_rng.gen_bool(RENEGE_CALL_CHANCE) {
+                self.call_renege(seat);
+                return;
+            }
+        }
+    }
+
+    // award points for the hand just played to the makers or defenders
+    fn score_hand(&mut self) {
+        if let Some(renege_seat) = self.table.renege_seat.clone() {
+            let penalty = self.rules.renege_penalty;
+            let mut names = vec![];
+            for team in self.get_opposing_teams_mut(renege_seat) {
+                team.game_score += i16::from(penalty);
+                names.push(team.name.clone());
+            }
+            self.log(format!(
+                "{} scored {} points for the renege",
+                names.join(" and "),
+                penalty
+            ));
+            return;
+        }
+        let Some(maker_seat) = self.table.maker_seat.clone() else {
+            return;
+        };
+        if let Some(bid) = self.table.bid {
+            self.score_bid_hand(maker_seat, bid);
+            return;
+        }
+        let maker_tricks = self.maker_tricks();
+        let maker_alone = self.table.alone_seat.is_some();
+        let defender_alone = self.table.defender_alone_seat.is_some();
+        let (mut maker_points, defender_points) = match self.rules.mode {
+            GameMode::Cutthroat => cutthroat_hand_points(maker_tricks),
+            _ => hand_points(
+                maker_tricks,
+                self.rules.mode.hand_size() as u8,
+                maker_alone,
+                defender_alone,
+            ),
+        };
+        let no_trump = self.high_suit == Suit::NoTrump;
+        if no_trump && maker_points > 0 {
+            maker_points += NO_TRUMP_BONUS;
+        }
+
+        let maker_seats = self.maker_seats();
+        let mut maker_names = vec![];
+        let mut defender_names = vec![];
+        for team in self.teams.iter_mut() {
+            if team.seats.iter().any(|seat| maker_seats.contains(seat)) {
+                team.game_score += i16::from(maker_points);
+                maker_names.push(team.name.clone());
+            } else {
+                team.game_score += i16::from(defender_points);
+                defender_names.push(team.name.clone());
+            }
+        }
+        let makers_name = maker_names.join(" and ");
+        let defenders_name = defender_names.join(" and ");
+
+        let thrown_in = if self.table.thrown_in {
+            " (thrown in)"
+        } else {
+            ""
+        };
+        let no_trump = if no_trump { " with no trump" } else { "" };
+        let num_tricks = self.rules.mode.hand_size() as u8;
+        match maker_points {
+            0 if defender_alone => self.log(format!(
+                "{} euchred {} defending alone and scored {} points{}",
+                defenders_name, makers_name, defender_points, thrown_in
+            )),
+            0 => self.log(format!(
+                "{} euchred {} and scored {} points{}",
+                defenders_name, makers_name, defender_points, thrown_in
+            )),
+            1 => self.log(format!(
+                "{} took {} tricks and scored 1 point{}",
+                makers_name, maker_tricks, thrown_in
+            )),
+            _ if maker_tricks < num_tricks => self.log(format!(
+                "{} took {} tricks and scored {} points{}{}",
+                makers_name, maker_tricks, maker_points, no_trump, thrown_in
+            )),
+            _ if maker_alone => self.log(format!(
+                "{} marched alone and scored {} points{}",
+                makers_name, maker_points, no_trump
+            )),
+            _ => self.log(format!(
+                "{} marched and scored {} points{}",
+                makers_name, maker_points, no_trump
+            )),
+        }
+    }
+
+    // score a bid hand, setting the bidders back if they fell short of their bid
+    fn score_bid_hand(&mut self, maker_seat: Seat, bid: Bid) {
+        let num_tricks = self.rules.mode.hand_size() as u8;
+        let maker_tricks = self.get_team_by_seat(maker_seat.clone()).hand_score;
+        let (maker_points, defender_points) = bid_hand_points(bid, maker_tricks, num_tricks);
+
+        let makers = self.get_team_by_seat_mut(maker_seat.clone());
+        makers.game_score += maker_points;
+        let makers_name = makers.name.clone();
+        let mut defender_names = vec![];
+        for defenders in self.get_opposing_teams_mut(maker_seat) {
+            defenders.game_score += i16::from(defender_points);
+            defender_names.push(defenders.name.clone());
+        }
+        let defenders_name = defender_names.join(" and ");
+
+        if maker_points < 0 {
+            self.log(format!(
+                "{} missed their bid of {} and were set back {} points",
+                makers_name, bid, -maker_points
+            ));
+        } else {
+            self.log(format!(
+                "{} made their bid of {} and scored {} points, {} scored {}",
+                makers_name, bid, maker_points, defenders_name, defender_points
+            ));
+        }
+    }
+
+    // the teams with the top score once it reaches the winning score, a called partner usually
+    // goes out on the same hand as the maker, so every team tied at the top shares the win
+    pub fn winning_teams(&self) -> Vec<&Team> {
+        let Some(top_score) = self.teams.iter().map(|team| team.game_score).max() else {
+            return vec![];
+        };
+        if top_score < i16::from(self.rules.winning_score) {
+            return vec![];
+        }
+        self.teams
+            .iter()
+            .filter(|team| team.game_score == top_score)
+            .collect()
+    }
+
+    // collect all cards and clear the results of the previous hand
+    fn reset_hand(&mut self) {
+        self.hand_num += 1;
+        self.deck = self.hand_deck();
+        for player in self.players.values_mut() {
+            player.hand.clear();
+        }
+        self.turned_down_suit = Suit::_None;
+        self.high_suit = Suit::_None;
+        self.table.clear();
+        for team in self.teams.iter_mut() {
+            team.hand_score = 0;
+        }
+    }
+
+    // a fresh deck for the current hand, shuffled from the seed and the hand number alone
+    fn hand_deck(&self) -> Deck {
+        seeded_deck(&self.rules, self.seed, u64::from(self.hand_num) + 1)
     }
 
-    pub fn handle_game_tick(&mut self, mut tick_count: u64) -> u64 {
+    /// Apply a player's decision to the game
+    ///
+    /// # Arguments
+    ///
+    /// * `seat` - The seat of the player making the decision
+    /// * `action` - The decision to apply
+    ///
+    /// # Returns
+    ///
+    /// The events that followed from the action, or why it was rejected, in which case the game is
+    /// left as it was
+    pub fn apply(&mut self, seat: Seat, action: GameAction) -> Result<Vec<GameEvent>, RuleError> {
+        let prev_state = self.state;
+        // anyone still in the hand may call out a renege, whoever's turn it is
+        if action == GameAction::CallRenege {
+            if self.rules.renege != RenegeRule::CallOut
+                || self.state != GameState::PlayingHand
+                || !self.players.contains_key(&seat)
+                || self.is_sitting_out(&seat)
+            {
+                return Err(RuleError::NotAllowedNow);
+            }
+            self.call_renege(seat);
+            return Ok(self.take_events(prev_state));
+        }
+        if !self.is_awaiting_action() {
+            return Err(RuleError::NotAllowedNow);
+        }
+        if seat != self.current_player_seat {
+            return Err(RuleError::NotYourTurn);
+        }
+        match (self.state, action) {
+            (GameState::CallingMisdeal, GameAction::CallMisdeal(misdeal)) => {
+                self.call_misdeal(misdeal)?
+            }
+            (GameState::SwappingFarmersHand, GameAction::SwapFarmersHand(swap)) => {
+                self.swap_farmers_hand(swap)?
+            }
+            (GameState::CallingPickup, GameAction::OrderUp { alone }) => self.order_up(alone)?,
+            (
+                GameState::CallingPickup | GameState::CallingHighSuit | GameState::Bidding,
+                GameAction::Pass,
+            ) => self.pass()?,
+            (GameState::CallingHighSuit, GameAction::CallSuit { suit, alone }) => {
+                self.call_suit(suit, alone)?
+            }
+            (GameState::Bidding, GameAction::Bid(bid)) => self.bid(bid)?,
+            (GameState::NamingTrump, GameAction::NameTrump { suit, alone }) => {
+                self.name_trump(suit, alone)?
+            }
+            (GameState::CallingAce, GameAction::CallAce(suit)) => self.call_ace(suit)?,
+            (
+                GameState::DealerDiscarding | GameState::LonerDiscarding,
+                GameAction::Discard(card_idx),
+            ) => self.discard(card_idx)?,
+            (GameState::PassingToLoner, GameAction::PassToLoner(card_idx)) => {
+                self.pass_to_loner(card_idx)?
+            }
+            (GameState::DefendingAlone, GameAction::DefendAlone(alone)) => self.defend_alone(alone),
+            (GameState::PlayingHand, GameAction::PlayCard(card_idx)) => self.play_card(card_idx)?,
+            (GameState::PlayingHand, GameAction::ClaimTricks) => self.claim_tricks()?,
+            _ => return Err(RuleError::NotAllowedNow),
+        }
+        Ok(self.take_events(prev_state))
+    }
+
+    // hand back the events since the last action or step, noting any change of state
+    fn take_events(&mut self, prev_state: GameState) -> Vec<GameEvent> {
+        if self.state != prev_state {
+            self.events.push(GameEvent::StateChanged(self.state));
+        }
+        self.events.drain(..).collect()
+    }
+
+    // decide the current player's action as a bot would, if the game is waiting on one
+    pub fn bot_action(&mut self) -> Option<GameAction> {
+        if !self.is_awaiting_action() {
+            return None;
+        }
+        // reneging is only worth the risk when it can be called out rather than prevented
+        let try_renege = self.state == GameState::PlayingHand
+            && self.rules.renege == RenegeRule::CallOut
+            && self.bot_rng.gen_bool(BOT_RENEGE_CHANCE);
+        let player = self.current_player();
+        let action = match self.state {
+            GameState::CallingMisdeal => GameAction::CallMisdeal(true),
+            GameState::SwappingFarmersHand => GameAction::SwapFarmersHand(true),
+            GameState::CallingPickup => {
+                let upcard = self.table.upcard.expect("no upcard to call");
+                let is_dealer = self.current_player_seat == self.table.dealer_seat;
+                let is_dealer_team = self
+                    .get_team_by_seat(self.table.dealer_seat.clone())
+                    .seats
+                    .contains(&self.current_player_seat);
+                if player.call_pickup(upcard, is_dealer, is_dealer_team) {
+                    GameAction::OrderUp {
+                        alone: self.can_go_alone() && player.call_alone(upcard.suit),
+                    }
+                } else {
+                    GameAction::Pass
+                }
+            }
+            GameState::DealerDiscarding | GameState::LonerDiscarding => {
+                GameAction::Discard(player.choose_discard(self.high_suit))
+            }
+            GameState::CallingHighSuit => match player.call_suit(
+                self.turned_down_suit,
+                self.can_call_no_trump(),
+                self.is_dealer_stuck(),
+            ) {
+                Some(suit) => GameAction::CallSuit {
+                    suit,
+                    alone: self.can_go_alone() && player.call_alone(suit),
+                },
+                None => GameAction::Pass,
+            },
+            GameState::Bidding => {
+                let bid = self.min_bid().and_then(|min_bid| {
+                    player.choose_bid(
+                        min_bid,
+                        self.rules.mode.hand_size() as u8,
+                        self.can_bid(Bid::Pepper),
+                        self.is_dealer_stuck(),
+                    )
+                });
+                match bid {
+                    Some(bid) => GameAction::Bid(bid),
+                    None => GameAction::Pass,
+                }
+            }
+            GameState::NamingTrump => {
+                let suit = player.name_trump(self.can_name_no_trump());
+                GameAction::NameTrump {
+                    suit,
+                    alone: self.can_name_alone() && player.call_alone(suit),
+                }
+            }
+            GameState::CallingAce => GameAction::CallAce(player.call_ace(self.high_suit)),
+            GameState::PassingToLoner => {
+                GameAction::PassToLoner(player.choose_pass(self.high_suit))
+            }
+            GameState::DefendingAlone => {
+                GameAction::DefendAlone(player.defend_alone(self.high_suit))
+            }
+            GameState::PlayingHand => {
+                if let Some(card_idx) = self.forced_card_idx() {
+                    return Some(GameAction::PlayCard(card_idx));
+                }
+                if player.hand.len() > 1 && self.can_claim() {
+                    return Some(GameAction::ClaimTricks);
+                }
+                let lead_suit = self.table.trick.lead_suit(self.high_suit);
+                if let Some(card_idx) = player
+                    .choose_renege(lead_suit, self.high_suit)
+                    .filter(|_| try_renege)
+                {
+                    return Some(GameAction::PlayCard(card_idx));
+                }
+                let winning_play = self.table.trick.winning_play(self.high_suit).cloned();
+                let partner_seat = self.known_partner_seat(self.current_player_seat.clone());
+                let partner_winning = winning_play
+                    .as_ref()
+                    .is_some_and(|(seat, _)| Some(seat) == partner_seat.as_ref());
+                GameAction::PlayCard(player.choose_card(
+                    self.table.trick.lead_suit(self.high_suit),
+                    self.high_suit,
+                    winning_play.map(|(_, card)| card),
+                    partner_winning,
+                ))
+            }
+            _ => return None,
+        };
+        Some(action)
+    }
+
+    // take the next automatic step, like dealing a packet of cards, clearing a finished trick or
+    // scoring the hand, how long to wait between steps is up to the caller
+    pub fn advance(&mut self) -> Vec<GameEvent> {
+        let prev_state = self.state;
         match self.state {
             GameState::PickingDealer => {
-                if self.hand_num == 0 {
-                    // TODO: implement picking first dealer by first black jack, then recreating the deck
-                    self.dealer_seat = rand::random();
+                let black_jack_seat = self
+                    .table
+                    .first_dealer_cards
+                    .last()
+                    .filter(|(_, card)| card.is_black_jack())
+                    .map(|(seat, _)| seat.clone());
+                if self.hand_num > 0 {
+                    self.table.dealer_seat = self.next_seat(self.table.dealer_seat.clone());
+                    self.current_player_seat = self.next_seat(self.table.dealer_seat.clone());
+                    self.state = GameState::DealingHand;
+                } else if let Some(seat) = black_jack_seat {
+                    self.table.dealer_seat = seat;
+                    self.log(format!(
+                        "{} deals first",
+                        self.get_player_in_seat(self.table.dealer_seat.clone()).name
+                    ));
+                    self.table.first_dealer_cards.clear();
+                    self.deck = self.hand_deck();
+                    self.current_player_seat = self.next_seat(self.table.dealer_seat.clone());
+                    self.state = GameState::DealingHand;
                 } else {
-                    self.dealer_seat = self.dealer_seat.next();
+                    // deal face up cards around the table until a black jack appears
+                    if self.table.first_dealer_cards.is_empty() {
+                        self.log("The first black jack deals".to_string());
+                    }
+                    if let Some(card) = self.deck.deal(1).pop() {
+                        self.table
+                            .first_dealer_cards
+                            .push((self.current_player_seat.clone(), card));
+                    }
+                    self.next_turn();
                 }
-                self.current_player_seat = self.dealer_seat.next();
-                self.state = GameState::DealingHand;
             }
             GameState::DealingHand => {
-                // TODO: deal the "appropriate" way (2, 3, 2, 3, 3, 2, 3, 2)
-                if tick_count >= 5 {
-                    if self.current_player().hand.is_empty() {
-                        self.current_player_mut().hand = self.deck.deal(5);
-                        self.next_turn();
+                let hand_size = self.rules.mode.hand_size();
+                let hand_len = self.current_player().hand.len();
+                if hand_len < hand_size {
+                    // deal packets of 3-2-3-2 around the table, then 2-3-2-3
+                    let num_cards = if hand_len == 0 {
+                        let num_dealt = self
+                            .players
+                            .values()
+                            .filter(|player| !player.hand.is_empty())
+                            .count();
+                        if num_dealt % 2 == 0 {
+                            hand_size.div_ceil(2)
+                        } else {
+                            hand_size / 2
+                        }
                     } else {
-                        // TODO: display face up card and deck on the table
-                        self.current_player_seat = self.dealer_seat.next();
-                        self.state = GameState::CallingPickup;
+                        hand_size - hand_len
+                    };
+                    let cards = self.deck.deal(num_cards);
+                    self.current_player_mut().hand.extend(cards);
+                    self.next_turn();
+                } else {
+                    // the rest of the deck becomes the kitty
+                    let kitty = self.deck.deal(self.deck.cards.len());
+                    self.table.set_kitty(kitty);
+                    if let Some(upcard) = self.table.upcard {
+                        self.log(format!("The {} is turned up", upcard.get_name()));
                     }
-                    tick_count = 0
+                    self.check_ace_no_face(self.next_seat(self.table.dealer_seat.clone()));
+                }
+            }
+            GameState::PlayingHand if self.is_trick_complete() => {
+                self.bots_call_renege();
+                if self.state == GameState::PlayingHand {
+                    self.table.collect_trick();
+                    if self.current_player().hand.is_empty() {
+                        self.state = GameState::ScoringHand;
+                    } else if self.rules.throw_in == ThrowInRule::ThrowIn && self.is_hand_decided()
+                    {
+                        self.throw_in();
+                    }
+                }
+            }
+            GameState::ScoringHand => {
+                self.score_hand();
+                let winning_teams = self.winning_teams();
+                if let Some(winning_team) = winning_teams.first() {
+                    let winning_score = winning_team.game_score;
+                    let winning_names: Vec<String> =
+                        winning_teams.iter().map(|team| team.name.clone()).collect();
+                    let runner_up_score = self
+                        .teams
+                        .iter()
+                        .map(|team| team.game_score)
+                        .filter(|score| *score < winning_score)
+                        .max()
+                        .unwrap_or_default();
+                    let message = format!(
+                        "{} won the game {} to {}!",
+                        winning_names.join(" and "),
+                        winning_score,
+                        runner_up_score
+                    );
+                    self.log(message);
+                    self.state = GameState::GameOver;
                 } else {
-                    tick_count += 1
+                    self.reset_hand();
+                    self.state = GameState::PickingDealer;
                 }
             }
-            GameState::CallingPickup => {}
-            GameState::CallingHighSuit => {}
-            GameState::PlayingHand => {}
+            // everything else waits on a player's action
+            _ => {}
         }
-        tick_count
+        self.take_events(prev_state)
+    }
+}
+
+/// Return the points awarded to the makers and defenders for a hand
+///
+/// # Arguments
+///
+/// * `maker_tricks` - The number of tricks taken by the makers
+/// * `num_tricks` - The number of tricks in the hand
+/// * `maker_alone` - Whether the maker played the hand alone
+/// * `defender_alone` - Whether a defender played the hand alone
+///
+/// # Returns
+///
+/// The points for the makers and the points for the defenders
+pub fn hand_points(
+    maker_tricks: u8,
+    num_tricks: u8,
+    maker_alone: bool,
+    defender_alone: bool,
+) -> (u8, u8) {
+    match maker_tricks {
+        tricks if tricks == num_tricks && maker_alone => (4, 0),
+        tricks if tricks == num_tricks => (2, 0),
+        tricks if tricks > num_tricks / 2 => (1, 0),
+        _ if defender_alone => (0, 4),
+        _ => (0, 2),
+    }
+}
+
+/// Return the points awarded to the maker and to each defender for a cutthroat hand
+///
+/// The maker always plays alone against the other two players, who each score for a euchre.
+///
+/// # Arguments
+///
+/// * `maker_tricks` - The number of tricks taken by the maker
+///
+/// # Returns
+///
+/// The points for the maker and the points for each defender
+pub fn cutthroat_hand_points(maker_tricks: u8) -> (u8, u8) {
+    match maker_tricks {
+        5 => (3, 0),
+        3 | 4 => (1, 0),
+        _ => (0, 2),
     }
 }
 
+/// Return the points awarded to the bidders and to each defending team for a bid hand
+///
+/// Bidders who make their bid score every trick they took, or double the hand for pepper, and
+/// are set back by the bid when they miss it. Defenders always score the tricks they took.
+///
+/// # Arguments
+///
+/// * `bid` - The winning bid
+/// * `maker_tricks` - The number of tricks taken by the bidders
+/// * `num_tricks` - The number of tricks in the hand
+///
+/// # Returns
+///
+/// The points for the bidders and the points for each defending team
+pub fn bid_hand_points(bid: Bid, maker_tricks: u8, num_tricks: u8) -> (i16, u8) {
+    let defender_points = num_tricks.saturating_sub(maker_tricks);
+    let maker_points = match bid {
+        Bid::Pepper if maker_tricks == num_tricks => 2 * i16::from(num_tricks),
+        Bid::Pepper => -2 * i16::from(num_tricks),
+        Bid::Tricks(tricks) if maker_tricks >= tricks => i16::from(maker_tricks),
+        Bid::Tricks(tricks) => -i16::from(tricks),
+    };
+    (maker_points, defender_points)
+}
+
+/// Check whether the rest of a hand can change the points it scores
+///
+/// # Arguments
+///
+/// * `maker_tricks` - The number of tricks taken by the makers so far
+/// * `tricks_left` - The number of tricks left to play
+/// * `num_tricks` - The number of tricks in the hand
+/// * `maker_alone` - Whether the maker is playing the hand alone
+/// * `defender_alone` - Whether a defender is playing the hand alone
+///
+/// # Returns
+///
+/// Whether every way the remaining tricks could fall scores the same points
+pub fn hand_decided(
+    maker_tricks: u8,
+    tricks_left: u8,
+    num_tricks: u8,
+    maker_alone: bool,
+    defender_alone: bool,
+) -> bool {
+    let points = hand_points(maker_tricks, num_tricks, maker_alone, defender_alone);
+    (maker_tricks..=maker_tricks + tricks_left)
+        .all(|tricks| hand_points(tricks, num_tricks, maker_alone, defender_alone) == points)
+}
+
+/// Check whether a hand on lead is sure to take every remaining trick
+///
+/// The claim must hold no matter how the unseen cards are held. Any opponent could hold any of
+/// them, so each card in hand must beat every unseen card of its suit, and any off suit card
+/// needs enough trump led ahead of it to pull every unseen trump.
+///
+/// # Arguments
+///
+/// * `hand` - The cards in the claiming player's hand
+/// * `unseen` - The cards the claiming player has not seen played
+/// * `high_suit` - The suit that is high for the hand
+///
+/// # Returns
+///
+/// Whether the claim holds against every possible holding of the unseen cards
+pub fn claim_holds(hand: &[Card], unseen: &[Card], high_suit: Suit) -> bool {
+    let beats_unseen = |card: &Card| {
+        let suit = card.get_effective_suit(high_suit);
+        unseen
+            .iter()
+            .filter(|other| other.get_effective_suit(high_suit) == suit)
+            .all(|other| card.get_rank(high_suit, suit) > other.get_rank(high_suit, suit))
+    };
+    let (trumps, off_suits): (Vec<Card>, Vec<Card>) = hand
+        .iter()
+        .partition(|card| card.get_effective_suit(high_suit) == high_suit);
+    let num_unseen_trumps = unseen
+        .iter()
+        .filter(|card| card.get_effective_suit(high_suit) == high_suit)
+        .count();
+    hand.iter().all(beats_unseen) && (off_suits.is_empty() || num_unseen_trumps <= trumps.len())
+}
+
+// shuffle a deck from its own stream of the seed, the deck that picks the first dealer is stream
+// zero and each hand after it counts up from one
+fn seeded_deck(rules: &RuleSet, seed: u64, stream: u64) -> Deck {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    rng.set_stream(stream);
+    Deck::new(&rules.faces(), rules.uses_joker(), &mut rng)
+}
+
 impl Default for Game {
     fn default() -> Self {
         Game::new(
-            "User".to_string(),
-            "Partner".to_string(),
-            "Opponent 1".to_string(),
-            "Opponent 2".to_string(),
-            "Good Guys".to_string(),
-            "Bad Guys".to_string(),
+            vec![
+                "User".to_string(),
+                "Opponent 1".to_string(),
+                "Partner".to_string(),
+                "Opponent 2".to_string(),
+            ],
+            vec!["Good Guys".to_string(), "Bad Guys".to_string()],
+            RuleSet::default(),
+            rand::random(),
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::{card::Face, rules::DeckRule};
+
+    #[test]
+    fn test_hand_points() {
+        assert!(hand_points(0, 5, false, false) == (0, 2));
+        assert!(hand_points(2, 5, false, false) == (0, 2));
+        assert!(hand_points(3, 5, false, false) == (1, 0));
+        assert!(hand_points(4, 5, false, false) == (1, 0));
+        assert!(hand_points(5, 5, false, false) == (2, 0));
+
+        assert!(hand_points(2, 5, true, false) == (0, 2));
+        assert!(hand_points(3, 5, true, false) == (1, 0));
+        assert!(hand_points(5, 5, true, false) == (4, 0));
+
+        assert!(hand_points(2, 5, true, true) == (0, 4));
+        assert!(hand_points(4, 5, true, true) == (1, 0));
+        assert!(hand_points(5, 5, true, true) == (4, 0));
+
+        // six-handed hands are four tricks, so two is a euchre
+        assert!(hand_points(2, 4, false, false) == (0, 2));
+        assert!(hand_points(3, 4, false, false) == (1, 0));
+        assert!(hand_points(4, 4, false, false) == (2, 0));
+    }
+
+    fn card(face: Face, suit: Suit) -> Card {
+        Card { face, suit }
+    }
+
+    fn player_names(num_players: usize) -> Vec<String> {
+        (0..num_players)
+            .map(|idx| format!("Player {}", idx))
+            .collect()
+    }
+
+    // start a seeded game with a player in every seat the rules call for
+    fn new_game(rules: RuleSet) -> Game {
+        Game::new(player_names(rules.mode.seats().len()), vec![], rules, 0)
+    }
+
+    // advance through the automatic steps to the next decision
+    fn advance_to_action(game: &mut Game) {
+        while !game.is_awaiting_action() && game.state != GameState::GameOver {
+            game.advance();
+        }
+    }
+
+    // have the current player take an action that must be allowed
+    fn act(game: &mut Game, action: GameAction) {
+        let seat = game.current_player_seat.clone();
+        game.apply(seat, action).expect("action rejected");
+    }
+
+    // have the current player play the given card from their hand
+    fn play(game: &mut Game, card: Card) {
+        let card_idx = game
+            .current_player()
+            .hand
+            .iter()
+            .position(|held| *held == card)
+            .expect("card not found");
+        act(game, GameAction::PlayCard(card_idx));
+    }
+
+    // play out the hand with the first legal card in each hand
+    fn play_out_hand(game: &mut Game) {
+        while game.state == GameState::PlayingHand {
+            if game.is_trick_complete() {
+                game.advance();
+                continue;
+            }
+            let lead_suit = game.table.trick.lead_suit(game.high_suit);
+            let legal_idxs = game
+                .current_player()
+                .legal_card_idxs(lead_suit, game.high_suit);
+            act(game, GameAction::PlayCard(legal_idxs[0]));
+        }
+    }
+
+    // deal a four-handed hand to the first decision, the first and second hands go to the two
+    // players left of the dealer, the third player holds the low diamonds, the dealer holds the
+    // off aces and the nine of spades is turned up
+    fn deal_hands(game: &mut Game, first_hand: [Card; 5], second_hand: [Card; 5]) {
+        advance_to_action(game);
+        let dealer_seat = game.table.dealer_seat.clone();
+        let first_seat = game.next_seat(dealer_seat.clone());
+        let second_seat = game.next_seat(first_seat.clone());
+        let third_seat = game.next_seat(second_seat.clone());
+        let hands = [
+            (first_seat, first_hand.to_vec()),
+            (second_seat, second_hand.to_vec()),
+            (
+                third_seat,
+                vec![
+                    card(Face::Nine, Suit::Diamonds),
+                    card(Face::Ten, Suit::Diamonds),
+                    card(Face::Jack, Suit::Diamonds),
+                    card(Face::Queen, Suit::Diamonds),
+                    card(Face::King, Suit::Diamonds),
+                ],
+            ),
+            (
+                dealer_seat,
+                vec![
+                    card(Face::Ace, Suit::Hearts),
+                    card(Face::Ace, Suit::Diamonds),
+                    card(Face::Ace, Suit::Clubs),
+                    card(Face::King, Suit::Clubs),
+                    card(Face::Ten, Suit::Spades),
+                ],
+            ),
+        ];
+        for (seat, hand) in hands {
+            game.players.get_mut(&seat).expect("player not found").hand = hand;
+        }
+        game.table.upcard = Some(card(Face::Nine, Suit::Spades));
+    }
+
+    #[test]
+    fn test_first_black_jack_deals() {
+        let mut game = new_game(RuleSet::default());
+        let first_seat = game.current_player_seat.clone();
+        let mut dealt = vec![];
+        while game.state == GameState::PickingDealer {
+            dealt = game.table.first_dealer_cards.clone();
+            game.advance();
+        }
+
+        // cards go face up around the table until the first black jack
+        let (dealer_seat, black_jack) = dealt.last().cloned().expect("no cards dealt");
+        assert!(black_jack.is_black_jack());
+        assert!(dealt[..dealt.len() - 1]
+            .iter()
+            .all(|(_, card)| !card.is_black_jack()));
+        let mut seat = first_seat;
+        for (card_seat, _) in dealt.iter() {
+            assert!(*card_seat == seat);
+            seat = game.next_seat(seat);
+        }
+
+        // whoever gets it deals from a fresh deck
+        assert!(game.state == GameState::DealingHand);
+        assert!(game.table.dealer_seat == dealer_seat);
+        assert!(game.table.first_dealer_cards.is_empty());
+        assert!(game.deck.cards == game.hand_deck().cards);
+        assert!(game.deck.cards.len() == 24);
+    }
+
+    #[test]
+    fn test_deal_packets() {
+        let mut game = new_game(RuleSet::default());
+        while game.state == GameState::PickingDealer {
+            game.advance();
+        }
+        let dealer_seat = game.table.dealer_seat.clone();
+        let mut packets = vec![];
+        while game.state == GameState::DealingHand {
+            let seat = game.current_player_seat.clone();
+            let hand_len = game.current_player().hand.len();
+            game.advance();
+            let num_cards = game.get_player_in_seat(seat.clone()).hand.len() - hand_len;
+            if num_cards > 0 {
+                packets.push((seat, num_cards));
+            }
+        }
+
+        // 3-2-3-2 around from the left of the dealer, then 2-3-2-3
+        let mut seat = game.next_seat(dealer_seat);
+        let mut expected = vec![];
+        for num_cards in [3, 2, 3, 2, 2, 3, 2, 3] {
+            expected.push((seat.clone(), num_cards));
+            seat = game.next_seat(seat);
+        }
+        assert!(packets == expected);
+        assert!(game.table.upcard.is_some());
+        assert!(game.table.kitty.len() == 3);
+    }
+
+    #[test]
+    fn test_follow_suit() {
+        let hearts = [
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::Nine, Suit::Clubs),
+            card(Face::Ten, Suit::Clubs),
+        ];
+        let one_heart = [
+            card(Face::Queen, Suit::Clubs),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::Jack, Suit::Spades),
+            card(Face::Ace, Suit::Spades),
+            card(Face::King, Suit::Spades),
+        ];
+        let mut game = new_game(RuleSet::default());
+        deal_hands(&mut game, hearts, one_heart);
+        act(&mut game, GameAction::OrderUp { alone: false });
+        act(&mut game, GameAction::Discard(0));
+        play(&mut game, card(Face::Nine, Suit::Hearts));
+
+        // holding a heart, the club is refused and the hand is left as it was
+        let seat = game.current_player_seat.clone();
+        let hand = game.current_player().hand.clone();
+        let club = GameAction::PlayCard(0);
+        assert!(game.apply(seat, club) == Err(RuleError::MustFollow(Suit::Hearts)));
+        assert!(game.current_player().hand == hand);
+        assert!(game.table.trick.plays.len() == 1);
+        play(&mut game, card(Face::Queen, Suit::Hearts));
+        assert!(game.table.trick.plays.len() == 2);
+    }
+
+    #[test]
+    fn test_trick_winner() {
+        let trump_ace = [
+            card(Face::Ace, Suit::Spades),
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Nine, Suit::Clubs),
+            card(Face::Ten, Suit::Clubs),
+        ];
+        let left_bower = [
+            card(Face::Jack, Suit::Clubs),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::Queen, Suit::Clubs),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+        ];
+        let mut game = new_game(RuleSet::default());
+        deal_hands(&mut game, trump_ace, left_bower);
+        act(&mut game, GameAction::OrderUp { alone: false });
+        act(&mut game, GameAction::Discard(0));
+
+        // the left bower follows spades and beats the ace, the high diamond is off suit
+        let bower_seat = game.next_seat(game.current_player_seat.clone());
+        play(&mut game, card(Face::Ace, Suit::Spades));
+        play(&mut game, card(Face::Jack, Suit::Clubs));
+        play(&mut game, card(Face::King, Suit::Diamonds));
+        play(&mut game, card(Face::Ten, Suit::Spades));
+        game.advance();
+        assert!(game.table.tricks[0].winner_seat == Some(bower_seat.clone()));
+        assert!(game.get_team_by_seat(bower_seat.clone()).hand_score == 1);
+
+        // the winner leads the next trick
+        assert!(game.table.trick.plays.is_empty());
+        assert!(game.table.leader_seat == bower_seat);
+        assert!(game.current_player_seat == bower_seat);
+    }
+
+    #[test]
+    fn test_joker_upcard_alone() {
+        let rules = RuleSet {
+            joker: true,
+            ..RuleSet::default()
+        };
+        let spades = [
+            card(Face::Nine, Suit::Spades),
+            card(Face::Ten, Suit::Spades),
+            card(Face::Queen, Suit::Spades),
+            card(Face::King, Suit::Spades),
+            card(Face::Ace, Suit::Spades),
+        ];
+        let hearts = [
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+        ];
+
+        // with loners off the dealer names trump with their partner
+        let mut game = new_game(RuleSet {
+            loners_allowed: false,
+            ..rules.clone()
+        });
+        deal_hands(&mut game, spades, hearts);
+        game.table.upcard = Some(Card::joker());
+        game.start_calling();
+        let dealer_seat = game.table.dealer_seat.clone();
+        let name_alone = GameAction::NameTrump {
+            suit: Suit::Clubs,
+            alone: true,
+        };
+        assert!(game.apply(dealer_seat.clone(), name_alone) == Err(RuleError::LonersNotAllowed));
+        assert!(game.state == GameState::NamingTrump);
+        assert!(game.table.upcard == Some(Card::joker()));
+
+        // the dealer takes the joker and goes alone, their partner sits out
+        let mut game = new_game(rules);
+        deal_hands(&mut game, spades, hearts);
+        game.table.upcard = Some(Card::joker());
+        game.start_calling();
+        let dealer_seat = game.table.dealer_seat.clone();
+        assert!(game.state == GameState::NamingTrump);
+        assert!(game.current_player_seat == dealer_seat);
+        act(&mut game, name_alone);
+        assert!(game.high_suit == Suit::Clubs);
+        assert!(game.table.alone_seat == Some(dealer_seat.clone()));
+        let partner_seat = game
+            .get_partner_seat(dealer_seat.clone())
+            .expect("no partner");
+        assert!(game.is_sitting_out(&partner_seat));
+        assert!(game.state == GameState::DealerDiscarding);
+        assert!(game.current_player().hand.contains(&Card::joker()));
+    }
+
+    #[test]
+    fn test_loners_not_allowed() {
+        let mut game = new_game(RuleSet {
+            loners_allowed: false,
+            ..RuleSet::default()
+        });
+        advance_to_action(&mut game);
+        let seat = game.current_player_seat.clone();
+        let upcard = game.table.upcard;
+        let hand = game.current_player().hand.clone();
+
+        // a loner is refused rather than called with a partner
+        let order_up_alone = GameAction::OrderUp { alone: true };
+        assert!(game.apply(seat.clone(), order_up_alone) == Err(RuleError::LonersNotAllowed));
+        assert!(game.state == GameState::CallingPickup);
+        assert!(game.current_player_seat == seat);
+        assert!(game.table.upcard == upcard);
+        assert!(game.table.maker_seat.is_none());
+        assert!(game.current_player().hand == hand);
+
+        for _ in 0..4 {
+            act(&mut game, GameAction::Pass);
+        }
+        let seat = game.current_player_seat.clone();
+        let suit = *SUIT_VARIANTS
+            .iter()
+            .find(|suit| **suit != game.turned_down_suit)
+            .expect("suit not found");
+        let call_alone = GameAction::CallSuit { suit, alone: true };
+        assert!(game.apply(seat, call_alone) == Err(RuleError::LonersNotAllowed));
+        assert!(game.state == GameState::CallingHighSuit);
+        assert!(game.table.maker_seat.is_none());
+    }
+
+    #[test]
+    fn test_loner() {
+        let mut game = new_game(RuleSet::default());
+        let top_trumps = [
+            card(Face::Jack, Suit::Spades),
+            card(Face::Jack, Suit::Clubs),
+            card(Face::Ace, Suit::Spades),
+            card(Face::King, Suit::Spades),
+            card(Face::Queen, Suit::Spades),
+        ];
+        let hearts = [
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+        ];
+        deal_hands(&mut game, top_trumps, hearts);
+        let dealer_seat = game.table.dealer_seat.clone();
+        let loner_seat = game.current_player_seat.clone();
+        let defender_seat = game.next_seat(loner_seat.clone());
+        let partner_seat = game
+            .get_partner_seat(loner_seat.clone())
+            .expect("no partner");
+
+        // the partner folds their hand and the dealer still picks up
+        act(&mut game, GameAction::OrderUp { alone: true });
+        assert!(game.table.alone_seat == Some(loner_seat.clone()));
+        assert!(game.is_sitting_out(&partner_seat));
+        assert!(game
+            .get_player_in_seat(partner_seat.clone())
+            .hand
+            .is_empty());
+        act(&mut game, GameAction::Discard(0));
+
+        // tricks go around three players, skipping the partner
+        play_out_hand(&mut game);
+        assert!(game.state == GameState::ScoringHand);
+        assert!(game.table.tricks.len() == 5);
+        let first_trick_seats: Vec<Seat> = game.table.tricks[0]
+            .plays
+            .iter()
+            .map(|(seat, _)| seat.clone())
+            .collect();
+        assert!(first_trick_seats == vec![loner_seat.clone(), defender_seat, dealer_seat]);
+        assert!(game.table.tricks.iter().all(|trick| trick.plays.len() == 3));
+
+        // a lone march scores four
+        game.advance();
+        assert!(game.get_team_by_seat(loner_seat).game_score == 4);
+    }
+
+    #[test]
+    fn test_defend_alone() {
+        let mut game = new_game(RuleSet {
+            defend_alone: true,
+            ..RuleSet::default()
+        });
+        let no_trumps = [
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+        ];
+        let top_trumps = [
+            card(Face::Jack, Suit::Spades),
+            card(Face::Jack, Suit::Clubs),
+            card(Face::Ace, Suit::Spades),
+            card(Face::King, Suit::Spades),
+            card(Face::Queen, Suit::Spades),
+        ];
+        deal_hands(&mut game, no_trumps, top_trumps);
+        let dealer_seat = game.table.dealer_seat.clone();
+        let loner_seat = game.current_player_seat.clone();
+        let defender_seat = game.next_seat(loner_seat.clone());
+
+        // the first defender is asked once the dealer has discarded
+        act(&mut game, GameAction::OrderUp { alone: true });
+        act(&mut game, GameAction::Discard(0));
+        assert!(game.state == GameState::DefendingAlone);
+        assert!(game.current_player_seat == defender_seat);
+        act(&mut game, GameAction::DefendAlone(true));
+        assert!(game.table.defender_alone_seat == Some(defender_seat.clone()));
+        assert!(game.is_sitting_out(&dealer_seat));
+
+        // tricks are head to head
+        play_out_hand(&mut game);
+        assert!(game.table.tricks.len() == 5);
+        assert!(game.table.tricks.iter().all(|trick| trick.plays.len() == 2));
+
+        // euchring a loner while defending alone scores four
+        game.advance();
+        assert!(game.get_team_by_seat(defender_seat).game_score == 4);
+        assert!(game.get_team_by_seat(loner_seat).game_score == 0);
+    }
+
+    #[test]
+    fn test_railroad_pass_to_loner() {
+        let mut game = new_game(RuleSet {
+            mode: GameMode::Railroad,
+            ..RuleSet::default()
+        });
+        let trumps = [
+            card(Face::Jack, Suit::Spades),
+            card(Face::Jack, Suit::Clubs),
+            card(Face::Ace, Suit::Spades),
+            card(Face::King, Suit::Spades),
+            card(Face::Nine, Suit::Hearts),
+        ];
+        let hearts = [
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+            card(Face::Queen, Suit::Spades),
+        ];
+        deal_hands(&mut game, trumps, hearts);
+        let dealer_seat = game.table.dealer_seat.clone();
+        let loner_seat = game.current_player_seat.clone();
+        let partner_seat = game
+            .get_partner_seat(loner_seat.clone())
+            .expect("no partner");
+
+        // the partner stays in through the dealer's discard to pass the loner a card
+        act(&mut game, GameAction::OrderUp { alone: true });
+        assert!(!game.is_sitting_out(&partner_seat));
+        act(&mut game, GameAction::Discard(0));
+        assert!(game.state == GameState::PassingToLoner);
+        assert!(game.current_player_seat == partner_seat);
+        let pass = GameAction::PassToLoner(5);
+        assert!(game.apply(partner_seat.clone(), pass) == Err(RuleError::NoSuchCard(5)));
+        let passed_card = game.current_player().hand[4];
+        act(&mut game, GameAction::PassToLoner(4));
+        assert!(game.is_sitting_out(&partner_seat));
+
+        // the loner takes the card and discards back down to five
+        assert!(game.state == GameState::LonerDiscarding);
+        assert!(game.current_player_seat == loner_seat);
+        assert!(game.current_player().hand.len() == 6);
+        assert!(game.current_player().hand.contains(&passed_card));
+        act(&mut game, GameAction::Discard(4));
+        assert!(game.get_player_in_seat(loner_seat.clone()).hand.len() == 5);
+
+        // railroad always lets the defenders answer the loner
+        assert!(game.state == GameState::DefendingAlone);
+        act(&mut game, GameAction::DefendAlone(false));
+        assert!(game.current_player_seat == dealer_seat);
+        act(&mut game, GameAction::DefendAlone(false));
+        assert!(game.state == GameState::PlayingHand);
+        assert!(game.current_player_seat == loner_seat);
+    }
+
+    #[test]
+    fn test_order_up() {
+        let mut game = new_game(RuleSet::default());
+        advance_to_action(&mut game);
+        assert!(game.state == GameState::CallingPickup);
+        let upcard = game.table.upcard.expect("no upcard");
+        let dealer_seat = game.table.dealer_seat.clone();
+        let first_seat = game.current_player_seat.clone();
+        assert!(first_seat == game.next_seat(dealer_seat.clone()));
+
+        // passing moves the call to the left
+        act(&mut game, GameAction::Pass);
+        let caller_seat = game.current_player_seat.clone();
+        assert!(caller_seat == game.next_seat(first_seat.clone()));
+        let order_up = GameAction::OrderUp { alone: false };
+        assert!(game.apply(first_seat, order_up) == Err(RuleError::NotYourTurn));
+
+        act(&mut game, order_up);
+        assert!(game.high_suit == upcard.suit);
+        assert!(game
+            .maker_team()
+            .expect("no makers")
+            .seats
+            .contains(&caller_seat));
+
+        // the dealer picks up the upcard and discards down to five
+        assert!(game.state == GameState::DealerDiscarding);
+        assert!(game.current_player_seat == dealer_seat);
+        assert!(game.current_player().hand.len() == 6);
+        assert!(game.current_player().hand.contains(&upcard));
+        let discard = GameAction::Discard(6);
+        assert!(game.apply(dealer_seat.clone(), discard) == Err(RuleError::NoSuchCard(6)));
+        let discarded = game.current_player().hand[0];
+        act(&mut game, GameAction::Discard(0));
+        assert!(game.get_player_in_seat(dealer_seat.clone()).hand.len() == 5);
+        assert!(game.table.buried == vec![discarded]);
+        assert!(game.table.kitty.len() == 3);
+        assert!(game.state == GameState::PlayingHand);
+        assert!(game.current_player_seat == game.next_seat(dealer_seat));
+    }
+
+    #[test]
+    fn test_second_round() {
+        let mut game = new_game(RuleSet::default());
+        advance_to_action(&mut game);
+        let upcard = game.table.upcard.expect("no upcard");
+        let dealer_seat = game.table.dealer_seat.clone();
+        for _ in 0..4 {
+            act(&mut game, GameAction::Pass);
+        }
+
+        // the upcard is turned down and its suit can't be called
+        assert!(game.state == GameState::CallingHighSuit);
+        assert!(game.table.upcard.is_none());
+        assert!(game.table.buried == vec![upcard]);
+        assert!(game.turned_down_suit == upcard.suit);
+        let caller_seat = game.current_player_seat.clone();
+        assert!(caller_seat == game.next_seat(dealer_seat));
+        let turned_down = GameAction::CallSuit {
+            suit: upcard.suit,
+            alone: false,
+        };
+        assert!(
+            game.apply(caller_seat.clone(), turned_down) == Err(RuleError::TurnedDown(upcard.suit))
+        );
+
+        let suit = *SUIT_VARIANTS
+            .iter()
+            .find(|suit| **suit != upcard.suit)
+            .expect("suit not found");
+        act(&mut game, GameAction::CallSuit { suit, alone: false });
+        assert!(game.high_suit == suit);
+        assert!(game
+            .maker_team()
+            .expect("no makers")
+            .seats
+            .contains(&caller_seat));
+        assert!(game.state == GameState::PlayingHand);
+    }
+
+    #[test]
+    fn test_no_trump_calls() {
+        let no_trump = GameAction::CallSuit {
+            suit: Suit::NoTrump,
+            alone: false,
+        };
+
+        // no trump can't be called unless the rules allow it
+        let mut game = new_game(RuleSet::default());
+        advance_to_action(&mut game);
+        for _ in 0..4 {
+            act(&mut game, GameAction::Pass);
+        }
+        let caller_seat = game.current_player_seat.clone();
+        assert!(game.apply(caller_seat, no_trump) == Err(RuleError::SuitNotAllowed(Suit::NoTrump)));
+
+        // and only in the second round, once the upcard is turned down
+        let mut game = new_game(RuleSet {
+            no_trump: true,
+            ..RuleSet::default()
+        });
+        advance_to_action(&mut game);
+        let caller_seat = game.current_player_seat.clone();
+        assert!(game.apply(caller_seat, no_trump) == Err(RuleError::NotAllowedNow));
+        for _ in 0..4 {
+            act(&mut game, GameAction::Pass);
+        }
+        act(&mut game, no_trump);
+        assert!(game.high_suit == Suit::NoTrump);
+    }
+
+    #[test]
+    fn test_no_trump_bonus() {
+        let mut game = new_game(RuleSet {
+            no_trump: true,
+            ..RuleSet::default()
+        });
+        let spades = [
+            card(Face::Ace, Suit::Spades),
+            card(Face::King, Suit::Spades),
+            card(Face::Queen, Suit::Spades),
+            card(Face::Jack, Suit::Spades),
+            card(Face::Ten, Suit::Hearts),
+        ];
+        let clubs = [
+            card(Face::Nine, Suit::Clubs),
+            card(Face::Ten, Suit::Clubs),
+            card(Face::Jack, Suit::Clubs),
+            card(Face::Queen, Suit::Clubs),
+            card(Face::Nine, Suit::Hearts),
+        ];
+        deal_hands(&mut game, spades, clubs);
+        let caller_seat = game.current_player_seat.clone();
+        for _ in 0..4 {
+            act(&mut game, GameAction::Pass);
+        }
+        let no_trump = GameAction::CallSuit {
+            suit: Suit::NoTrump,
+            alone: false,
+        };
+        act(&mut game, no_trump);
+
+        // the dealer throws off the ace of hearts on the spades, so the march scores the bonus
+        play_out_hand(&mut game);
+        game.advance();
+        assert!(game.get_team_by_seat(caller_seat).game_score == 2 + NO_TRUMP_BONUS as i16);
+    }
+
+    #[test]
+    fn test_all_pass() {
+        // everyone passes twice and the deal moves to the left
+        let mut game = new_game(RuleSet::default());
+        advance_to_action(&mut game);
+        let dealer_seat = game.table.dealer_seat.clone();
+        for _ in 0..8 {
+            act(&mut game, GameAction::Pass);
+        }
+        assert!(game.state == GameState::PickingDealer);
+        advance_to_action(&mut game);
+        assert!(game.state == GameState::CallingPickup);
+        assert!(game.table.dealer_seat == game.next_seat(dealer_seat));
+
+        // the stuck dealer can't pass the second round
+        let mut game = new_game(RuleSet {
+            all_pass: AllPassRule::StickTheDealer,
+            ..RuleSet::default()
+        });
+        advance_to_action(&mut game);
+        let dealer_seat = game.table.dealer_seat.clone();
+        for _ in 0..7 {
+            act(&mut game, GameAction::Pass);
+        }
+        assert!(game.current_player_seat == dealer_seat);
+        assert!(game.is_dealer_stuck());
+        assert!(game.apply(dealer_seat.clone(), GameAction::Pass) == Err(RuleError::DealerStuck));
+        let suit = *SUIT_VARIANTS
+            .iter()
+            .find(|suit| **suit != game.turned_down_suit)
+            .expect("suit not found");
+        act(&mut game, GameAction::CallSuit { suit, alone: false });
+        assert!(game.table.maker_seat == Some(dealer_seat));
+        assert!(game.state == GameState::PlayingHand);
+    }
+
+    #[test]
+    fn test_call_misdeal() {
+        let nines_and_tens = [
+            card(Face::Nine, Suit::Spades),
+            card(Face::Ten, Suit::Spades),
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Nine, Suit::Clubs),
+        ];
+        let face_cards = [
+            card(Face::Jack, Suit::Spades),
+            card(Face::Queen, Suit::Spades),
+            card(Face::King, Suit::Spades),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+        ];
+        let mut game = new_game(RuleSet {
+            ace_no_face: true,
+            ..RuleSet::default()
+        });
+        deal_hands(&mut game, nines_and_tens, face_cards);
+        let dealer_seat = game.table.dealer_seat.clone();
+        let first_seat = game.next_seat(dealer_seat.clone());
+
+        // a kept hand is played as dealt
+        game.check_ace_no_face(first_seat.clone());
+        assert!(game.state == GameState::CallingMisdeal);
+        assert!(game.current_player_seat == first_seat);
+        act(&mut game, GameAction::CallMisdeal(false));
+        assert!(game.state == GameState::CallingPickup);
+        assert!(game.get_player_in_seat(first_seat.clone()).hand == nines_and_tens.to_vec());
+
+        // a misdeal throws the hand in and the same dealer deals again
+        game.check_ace_no_face(first_seat.clone());
+        let hand_num = game.hand_num;
+        act(&mut game, GameAction::CallMisdeal(true));
+        assert!(game.state == GameState::DealingHand);
+        assert!(game.hand_num == hand_num + 1);
+        advance_to_action(&mut game);
+        assert!(game.table.dealer_seat == dealer_seat);
+        assert!(game.players.values().all(|player| player.hand.len() == 5));
+    }
+
+    #[test]
+    fn test_swap_farmers_hand() {
+        let farmers_hand = [
+            card(Face::Nine, Suit::Spades),
+            card(Face::Ten, Suit::Spades),
+            card(Face::Nine, Suit::Hearts),
+            card(Face::King, Suit::Spades),
+            card(Face::Queen, Suit::Spades),
+        ];
+        let face_cards = [
+            card(Face::Jack, Suit::Spades),
+            card(Face::Ace, Suit::Spades),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+        ];
+        let mut game = new_game(RuleSet {
+            farmers_hand: true,
+            ..RuleSet::default()
+        });
+        deal_hands(&mut game, farmers_hand, face_cards);
+        let first_seat = game.next_seat(game.table.dealer_seat.clone());
+        let kitty = game.table.kitty.clone();
+
+        // a declined swap keeps the hand and calling starts
+        game.check_farmers_hand(first_seat.clone());
+        assert!(game.state == GameState::SwappingFarmersHand);
+        assert!(game.current_player_seat == first_seat);
+        act(&mut game, GameAction::SwapFarmersHand(false));
+        assert!(game.state == GameState::CallingPickup);
+        assert!(game.table.kitty == kitty);
+
+        // the nines and tens are swapped for the kitty
+        game.check_farmers_hand(first_seat.clone());
+        act(&mut game, GameAction::SwapFarmersHand(true));
+        assert!(game.state == GameState::CallingPickup);
+        let hand = &game.get_player_in_seat(first_seat).hand;
+        assert!(hand.len() == 5);
+        assert!(kitty.iter().all(|card| hand.contains(card)));
+        assert!(farmers_hand[..3]
+            .iter()
+            .all(|card| game.table.kitty.contains(card)));
+    }
+
+    #[test]
+    fn test_two_handed_deal() {
+        let mut game = new_game(RuleSet {
+            mode: GameMode::TwoHanded,
+            deck: DeckRule::ThirtyTwo,
+            farmers_hand: true,
+            ..RuleSet::default()
+        });
+        advance_to_action(&mut game);
+        // the sevens and eights are stripped, the rest of the deck beyond the upcard is set aside
+        assert!(game.state == GameState::CallingPickup);
+        assert!(game.players.values().all(|player| player.hand.len() == 5));
+        assert!(game.table.upcard.is_some());
+        assert!(game.table.kitty.len() == 13);
+        assert!(!game.can_go_alone());
+    }
+
+    #[test]
+    fn test_call_renege_seats() {
+        let mut game = new_game(RuleSet {
+            mode: GameMode::TwoHanded,
+            renege: RenegeRule::CallOut,
+            ..RuleSet::default()
+        });
+        game.state = GameState::PlayingHand;
+        // nobody sits on the left in two-handed
+        assert!(game.apply(Seat::Left, GameAction::CallRenege) == Err(RuleError::NotAllowedNow));
+        assert!(game.apply(Seat::Top, GameAction::CallRenege).is_ok());
+
+        let mut game = Game::default();
+        game.rules.renege = RenegeRule::CallOut;
+        game.state = GameState::PlayingHand;
+        // a partner sitting out a loner has folded their hand and can't call
+        game.table.sitting_out_seats.push(Seat::Top);
+        assert!(game.apply(Seat::Top, GameAction::CallRenege) == Err(RuleError::NotAllowedNow));
+        assert!(game.apply(Seat::Left, GameAction::CallRenege).is_ok());
+    }
+
+    #[test]
+    fn test_seat_ring() {
+        let game = new_game(RuleSet {
+            mode: GameMode::TwoHanded,
+            ..RuleSet::default()
+        });
+        assert!(game.teams.len() == 2);
+        assert!(game.next_seat(Seat::Bottom) == Seat::Top);
+        assert!(game.next_seat(Seat::Top) == Seat::Bottom);
+        assert!(game.get_partner_seat(Seat::Bottom).is_none());
+
+        let game = Game::default();
+        assert!(game.next_seat(Seat::Right) == Seat::Bottom);
+        assert!(game.get_partner_seat(Seat::Left) == Some(Seat::Right));
+
+        let rules = RuleSet {
+            mode: GameMode::SixHanded,
+            ..RuleSet::default()
+        };
+        let game = Game::new(player_names(6), vec!["Good Guys".to_string()], rules, 0);
+        assert!(game.teams.len() == 3);
+        assert!(game.teams[2].name == "Team 3");
+        assert!(game.deck.cards.len() == 28);
+        assert!(game.next_seat(Seat::BottomRight) == Seat::Bottom);
+        assert!(game.get_partner_seat(Seat::BottomLeft) == Some(Seat::TopRight));
+        assert!(game.get_partner_seat(Seat::TopLeft) == Some(Seat::BottomRight));
+    }
+
+    #[test]
+    fn test_call_ace_partner() {
+        let mut game = new_game(RuleSet {
+            mode: GameMode::CallAce,
+            ..RuleSet::default()
+        });
+        assert!(game.teams.len() == 4);
+        for player in game.players.values_mut() {
+            player.hand.clear();
+        }
+        let mut deal = |seat: Seat, cards: Vec<Card>| {
+            game.players.get_mut(&seat).expect("player not found").hand = cards;
+        };
+        deal(Seat::Bottom, vec![card(Face::Jack, Suit::Hearts)]);
+        deal(Seat::Left, vec![card(Face::Ace, Suit::Clubs)]);
+        // the left bower belongs to trump, not clubs
+        deal(Seat::Top, vec![card(Face::Jack, Suit::Clubs)]);
+        deal(Seat::Right, vec![card(Face::Queen, Suit::Clubs)]);
+
+        game.high_suit = Suit::Clubs;
+        game.state = GameState::CallingAce;
+        game.current_player_seat = Seat::Bottom;
+        let call_clubs = GameAction::CallAce(Suit::Clubs);
+        assert!(
+            game.apply(Seat::Bottom, call_clubs) == Err(RuleError::SuitNotAllowed(Suit::Clubs))
+        );
+        assert!(game.table.partner_suit.is_none());
+
+        game.high_suit = Suit::Spades;
+        game.table.maker_seat = Some(Seat::Bottom);
+        assert!(game.apply(Seat::Left, call_clubs) == Err(RuleError::NotYourTurn));
+        assert!(game.apply(Seat::Bottom, call_clubs).is_ok());
+        assert!(game.table.partner_seat == Some(Seat::Left));
+        assert!(game.maker_seats().len() == 2);
+        // the maker doesn't know their partner until the card is played
+        assert!(game.known_partner_seat(Seat::Bottom).is_none());
+        assert!(game.known_partner_seat(Seat::Left) == Some(Seat::Bottom));
+    }
+
+    #[test]
+    fn test_call_ace_no_partner() {
+        let mut game = new_game(RuleSet {
+            mode: GameMode::CallAce,
+            ..RuleSet::default()
+        });
+        let call_clubs = |game: &mut Game, hands: [Vec<Card>; 4], kitty: Vec<Card>| {
+            game.table.clear();
+            for (seat, hand) in game.seats.clone().iter().zip(hands) {
+                game.players.get_mut(seat).expect("player not found").hand = hand;
+            }
+            game.table.kitty = kitty;
+            game.high_suit = Suit::Spades;
+            game.state = GameState::CallingAce;
+            game.current_player_seat = Seat::Bottom;
+            game.table.maker_seat = Some(Seat::Bottom);
+            act(game, GameAction::CallAce(Suit::Clubs));
+        };
+
+        // the maker called a suit they hold the best card of
+        let hands = [
+            vec![card(Face::Ace, Suit::Clubs)],
+            vec![card(Face::King, Suit::Clubs)],
+            vec![card(Face::Queen, Suit::Clubs)],
+            vec![card(Face::Nine, Suit::Diamonds)],
+        ];
+        call_clubs(&mut game, hands, vec![]);
+        assert!(game.table.partner_suit == Some(Suit::Clubs));
+        assert!(game.table.partner_seat.is_none());
+        assert!(game.table.partner_card.is_none());
+        assert!(game.maker_seats() == vec![Seat::Bottom]);
+
+        // the best club is in the kitty, so the king doesn't make a partner either
+        let hands = [
+            vec![card(Face::Nine, Suit::Hearts)],
+            vec![card(Face::King, Suit::Clubs)],
+            vec![card(Face::Queen, Suit::Clubs)],
+            vec![card(Face::Nine, Suit::Diamonds)],
+        ];
+        call_clubs(&mut game, hands, vec![card(Face::Ace, Suit::Clubs)]);
+        assert!(game.table.partner_seat.is_none());
+        assert!(game.maker_seats() == vec![Seat::Bottom]);
+    }
+
+    #[test]
+    fn test_call_ace_shared_win() {
+        let mut game = new_game(RuleSet {
+            mode: GameMode::CallAce,
+            ..RuleSet::default()
+        });
+        for (team, score) in game.teams.iter_mut().zip([11, 11, 8, 3]) {
+            team.game_score = score;
+        }
+
+        // the maker and their called partner went out together
+        game.state = GameState::ScoringHand;
+        game.advance();
+        assert!(game.state == GameState::GameOver);
+        let winning_names: Vec<&str> = game
+            .winning_teams()
+            .iter()
+            .map(|team| team.name.as_str())
+            .collect();
+        assert!(winning_names == vec!["Player 0", "Player 1"]);
+        assert!(game.latest_message() == "Player 0 and Player 1 won the game 11 to 8!");
+    }
+
+    #[test]
+    fn test_cutthroat_defenders_win() {
+        let mut game = new_game(RuleSet {
+            mode: GameMode::Cutthroat,
+            ..RuleSet::default()
+        });
+        for (team, score) in game.teams.iter_mut().zip([9, 9, 8]) {
+            team.game_score = score;
+        }
+
+        // both defenders euchre the maker and go out on the same hand
+        game.table.maker_seat = Some(Seat::Right);
+        game.state = GameState::ScoringHand;
+        game.advance();
+        assert!(game.state == GameState::GameOver);
+        assert!(game.winning_teams().len() == 2);
+        assert!(game.latest_message() == "Player 0 and Player 1 won the game 11 to 8!");
+    }
+
+    #[test]
+    fn test_cutthroat_hand_points() {
+        assert!(cutthroat_hand_points(2) == (0, 2));
+        assert!(cutthroat_hand_points(3) == (1, 0));
+        assert!(cutthroat_hand_points(4) == (1, 0));
+        assert!(cutthroat_hand_points(5) == (3, 0));
+    }
+
+    #[test]
+    fn test_hand_decided() {
+        // three tricks could still become a march
+        assert!(!hand_decided(3, 2, 5, false, false));
+        // four tricks can't be euchred, but can still march
+        assert!(!hand_decided(4, 1, 5, false, false));
+        // a euchre is decided once the defenders take three
+        assert!(hand_decided(2, 0, 5, false, false));
+        assert!(hand_decided(1, 1, 5, false, false));
+        assert!(!hand_decided(2, 1, 5, false, false));
+        // nothing is decided before the first trick
+        assert!(!hand_decided(0, 5, 5, true, false));
+    }
+
+    #[test]
+    fn test_bid_hand_points() {
+        // bidders score every trick they take when they make the bid
+        assert!(bid_hand_points(Bid::Tricks(3), 3, 6) == (3, 3));
+        assert!(bid_hand_points(Bid::Tricks(3), 5, 6) == (5, 1));
+        // and are set back by the bid when they miss
+        assert!(bid_hand_points(Bid::Tricks(4), 3, 6) == (-4, 3));
+
+        assert!(bid_hand_points(Bid::Pepper, 6, 6) == (12, 0));
+        assert!(bid_hand_points(Bid::Pepper, 5, 6) == (-12, 1));
+    }
+
+    #[test]
+    fn test_seeded_deals() {
+        let mut game = new_game(RuleSet::default());
+        let mut other = new_game(RuleSet::default());
+        assert!(game.deck.cards == other.deck.cards);
+
+        // bot decisions draw from their own rng and leave the deals alone
+        for _ in 0..10 {
+            other.bot_rng.gen_bool(BOT_RENEGE_CHANCE);
+        }
+        game.reset_hand();
+        other.reset_hand();
+        assert!(game.deck.cards == other.deck.cards);
+
+        // every hand is shuffled afresh
+        let cards = game.deck.cards.clone();
+        game.reset_hand();
+        assert!(game.deck.cards != cards);
+    }
+
+    #[test]
+    fn test_bots_play_out_game() {
+        let mut game = new_game(RuleSet::default());
+        assert!(game.apply(Seat::Bottom, GameAction::Pass) == Err(RuleError::NotAllowedNow));
+
+        // no timers needed, every step is either a player's action or an automatic one
+        let mut num_steps = 0;
+        while game.state != GameState::GameOver && num_steps < 10_000 {
+            match game.bot_action() {
+                Some(action) => {
+                    let seat = game.current_player_seat.clone();
+                    let played = match action {
+                        GameAction::PlayCard(card_idx) => Some(GameEvent::CardPlayed {
+                            seat: seat.clone(),
+                            card: game.current_player().hand[card_idx],
+                        }),
+                        _ => None,
+                    };
+                    let events = game.apply(seat, action).expect("bot action rejected");
+                    if let Some(played) = played {
+                        assert!(events.contains(&played));
+                    }
+                }
+                None => {
+                    game.advance();
+                }
+            }
+            num_steps += 1;
+        }
+        assert!(game.state == GameState::GameOver);
+        assert!(!game.winning_teams().is_empty());
+    }
+
+    #[test]
+    fn test_claim_holds() {
+        let high_suit = Suit::Hearts;
+        let right = card(Face::Jack, Suit::Hearts);
+        let left = card(Face::Jack, Suit::Diamonds);
+        let off_ace = card(Face::Ace, Suit::Spades);
+
+        // bowers are sure winners, even if the rest of trump is out
+        let unseen = vec![
+            card(Face::Ace, Suit::Hearts),
+            card(Face::King, Suit::Spades),
+        ];
+        assert!(claim_holds(&[right, left], &unseen, high_suit));
+
+        // a higher trump could be held against the left bower
+        assert!(!claim_holds(&[left], &[right], high_suit));
+
+        // the off ace is safe once trump is drawn, but not before
+        let unseen = vec![
+            card(Face::Ten, Suit::Hearts),
+            card(Face::King, Suit::Spades),
+        ];
+        assert!(claim_holds(&[right, off_ace], &unseen, high_suit));
+        let unseen = vec![
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Nine, Suit::Hearts),
+            card(Face::King, Suit::Spades),
+        ];
+        assert!(!claim_holds(&[right, off_ace], &unseen, high_suit));
+
+        // an off king can lose to an unseen ace
+        let unseen = vec![off_ace];
+        assert!(!claim_holds(
+            &[right, card(Face::King, Suit::Spades)],
+            &unseen,
+            high_suit
+        ));
+    }
+
+    #[test]
+    fn test_forced_card() {
+        let hearts = [
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::Nine, Suit::Clubs),
+            card(Face::Ten, Suit::Clubs),
+        ];
+        let one_heart = [
+            card(Face::Queen, Suit::Clubs),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::Jack, Suit::Spades),
+            card(Face::Ace, Suit::Spades),
+            card(Face::King, Suit::Spades),
+        ];
+        let mut game = new_game(RuleSet {
+            auto_play: true,
+            ..RuleSet::default()
+        });
+        deal_hands(&mut game, hearts, one_heart);
+        act(&mut game, GameAction::OrderUp { alone: false });
+        act(&mut game, GameAction::Discard(0));
+
+        // any card may lead, so nothing is forced
+        assert!(game.forced_card_idx().is_none());
+        play(&mut game, card(Face::Nine, Suit::Hearts));
+
+        // the only heart is played for whoever holds it, user or bot
+        let forced_idx = 1;
+        assert!(game.forced_card_idx() == Some(forced_idx));
+        assert!(!game.is_awaiting_user());
+        assert!(game.bot_action() == Some(GameAction::PlayCard(forced_idx)));
+        game.rules.auto_play = false;
+        assert!(game.forced_card_idx().is_none());
+    }
+
+    #[test]
+    fn test_claim_tricks() {
+        let top_trumps = [
+            card(Face::Jack, Suit::Spades),
+            card(Face::Jack, Suit::Clubs),
+            card(Face::Ace, Suit::Spades),
+            card(Face::King, Suit::Spades),
+            card(Face::Queen, Suit::Spades),
+        ];
+        let hearts = [
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+        ];
+        let mut game = new_game(RuleSet::default());
+        deal_hands(&mut game, top_trumps, hearts);
+        let claimer_seat = game.current_player_seat.clone();
+        act(&mut game, GameAction::OrderUp { alone: false });
+        act(&mut game, GameAction::Discard(0));
+
+        // the top five trumps take every trick
+        act(&mut game, GameAction::ClaimTricks);
+        assert!(game.state == GameState::ScoringHand);
+        assert!(game.get_team_by_seat(claimer_seat).hand_score == 5);
+        assert!(game.players.values().all(|player| player.hand.is_empty()));
+    }
+
+    #[test]
+    fn test_claim_rejected() {
+        let off_bowers = [
+            card(Face::Ace, Suit::Spades),
+            card(Face::King, Suit::Spades),
+            card(Face::Queen, Suit::Spades),
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Hearts),
+        ];
+        let right_bower = [
+            card(Face::Jack, Suit::Spades),
+            card(Face::Jack, Suit::Hearts),
+            card(Face::Queen, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+            card(Face::Nine, Suit::Clubs),
+        ];
+        let mut game = new_game(RuleSet::default());
+        deal_hands(&mut game, off_bowers, right_bower);
+        let claimer_seat = game.current_player_seat.clone();
+        act(&mut game, GameAction::OrderUp { alone: false });
+        act(&mut game, GameAction::Discard(0));
+
+        // the right bower is still out, so play goes on untouched
+        let hand = game.current_player().hand.clone();
+        let claim = GameAction::ClaimTricks;
+        assert!(game.apply(claimer_seat.clone(), claim) == Err(RuleError::ClaimRejected));
+        assert!(game.state == GameState::PlayingHand);
+        assert!(game.current_player().hand == hand);
+        assert!(game.get_team_by_seat(claimer_seat).hand_score == 0);
+
+        // and tricks can only be claimed on lead
+        play(&mut game, card(Face::Ace, Suit::Spades));
+        let seat = game.current_player_seat.clone();
+        assert!(game.apply(seat, claim) == Err(RuleError::ClaimNotOnLead));
+    }
+}
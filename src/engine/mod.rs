@@ -1,6 +1,8 @@
+pub mod action;
 pub mod card;
 pub mod game;
 pub mod player;
+pub mod rules;
 pub mod table;
 pub mod team;
 
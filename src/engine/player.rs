@@ -1,6 +1,22 @@
-use crate::engine::card::Card;
+use crate::engine::{
+    card::{Card, Face, Suit, SUIT_VARIANTS},
+    table::Bid,
+};
 
-#[derive(Default)]
+// minimum hand strength for a bot to make trump
+const CALL_STRENGTH: u8 = 18;
+// minimum hand strength for a bot to play without a partner
+const ALONE_STRENGTH: u8 = 32;
+// minimum hand strength for a bot to defend a loner without a partner
+const DEFEND_ALONE_STRENGTH: u8 = 24;
+// hand strength a bot counts on for each trick it bids
+const TRICK_STRENGTH: u8 = 6;
+// minimum hand strength for a bot to bid pepper
+const PEPPER_STRENGTH: u8 = 36;
+// number of nines and tens that make a farmer's hand
+const FARMERS_HAND_SIZE: usize = 3;
+
+#[derive(Default, PartialEq)]
 pub enum PlayerType {
     User,
     #[default]
@@ -25,11 +41,261 @@ impl Player {
         }
     }
 
-    pub fn call_pickup() {}
+    // whether the hand has no face cards or aces, which may be thrown in for a misdeal
+    pub fn has_ace_no_face(&self) -> bool {
+        self.hand.iter().all(|card| is_farmers_card(*card))
+    }
+
+    // whether the hand has enough nines and tens to swap with the kitty
+    pub fn has_farmers_hand(&self) -> bool {
+        self.hand
+            .iter()
+            .filter(|card| is_farmers_card(**card))
+            .count()
+            >= FARMERS_HAND_SIZE
+    }
+
+    // remove the lowest nines and tens from hand to be swapped with the kitty
+    pub fn take_farmers_cards(&mut self, num_cards: usize) -> Vec<Card> {
+        let mut cards = vec![];
+        while cards.len() < num_cards.min(FARMERS_HAND_SIZE) {
+            let Some(idx) = (0..self.hand.len())
+                .filter(|idx| is_farmers_card(self.hand[*idx]))
+                .min_by_key(|idx| self.hand[*idx].face.get_rank())
+            else {
+                break;
+            };
+            cards.push(self.hand.remove(idx));
+        }
+        cards
+    }
+
+    // decide whether to order up the upcard (bots only)
+    pub fn call_pickup(&self, upcard: Card, is_dealer: bool, is_dealer_team: bool) -> bool {
+        let high_suit = upcard.suit;
+        let upcard_strength = card_strength(upcard, high_suit);
+        let strength = if is_dealer {
+            // evaluate the hand as it will be after picking up and discarding
+            let mut hand = self.hand.clone();
+            hand.push(upcard);
+            hand.remove(lowest_card_idx(&hand, high_suit));
+            hand_strength(&hand, high_suit)
+        } else if is_dealer_team {
+            hand_strength(&self.hand, high_suit) + upcard_strength / 2
+        } else {
+            hand_strength(&self.hand, high_suit).saturating_sub(upcard_strength / 2)
+        };
+        strength >= CALL_STRENGTH
+    }
+
+    // decide whether to go alone after making trump (bots only)
+    pub fn call_alone(&self, high_suit: Suit) -> bool {
+        hand_strength(&self.hand, high_suit) >= ALONE_STRENGTH
+    }
+
+    // decide whether to defend alone against a loner (bots only)
+    pub fn defend_alone(&self, high_suit: Suit) -> bool {
+        hand_strength(&self.hand, high_suit) >= DEFEND_ALONE_STRENGTH
+    }
+
+    // add the upcard to the dealer's hand
+    pub fn pickup_card(&mut self, card: Card) {
+        self.hand.push(card);
+    }
+
+    // choose which card to discard after picking up (bots only)
+    pub fn choose_discard(&self, high_suit: Suit) -> usize {
+        lowest_card_idx(&self.hand, high_suit)
+    }
+
+    // choose the best card to pass to a partner playing alone (bots only)
+    pub fn choose_pass(&self, high_suit: Suit) -> usize {
+        (0..self.hand.len())
+            .max_by_key(|idx| {
+                let card = self.hand[*idx];
+                card.get_rank(high_suit, card.get_effective_suit(high_suit))
+            })
+            .unwrap_or_default()
+    }
+
+    // remove a card from hand to be discarded
+    pub fn discard_card(&mut self, card_idx: usize) -> Card {
+        self.hand.remove(card_idx)
+    }
+
+    // decide which suit to call, if any, excluding the turned down suit (bots only)
+    pub fn call_suit(
+        &self,
+        turned_down_suit: Suit,
+        allow_no_trump: bool,
+        must_call: bool,
+    ) -> Option<Suit> {
+        let no_trump: &[Suit] = if allow_no_trump {
+            &[Suit::NoTrump]
+        } else {
+            &[]
+        };
+        let (suit, strength) = SUIT_VARIANTS
+            .iter()
+            .chain(no_trump)
+            .filter(|suit| **suit != turned_down_suit)
+            .map(|suit| (*suit, hand_strength(&self.hand, *suit)))
+            .max_by_key(|(_, strength)| *strength)?;
+        if must_call || strength >= CALL_STRENGTH {
+            Some(suit)
+        } else {
+            None
+        }
+    }
+
+    // decide what to bid, if anything, starting from the lowest bid allowed (bots only)
+    pub fn choose_bid(
+        &self,
+        min_bid: Bid,
+        num_tricks: u8,
+        can_pepper: bool,
+        must_bid: bool,
+    ) -> Option<Bid> {
+        let strength = hand_strength(&self.hand, self.name_trump(true));
+        let tricks = (strength / TRICK_STRENGTH).min(num_tricks);
+        if can_pepper && strength >= PEPPER_STRENGTH {
+            Some(Bid::Pepper)
+        } else if must_bid || Bid::Tricks(tricks) >= min_bid {
+            Some(min_bid)
+        } else {
+            None
+        }
+    }
+
+    // decide which suit to name as high, or no trump when allowed (bots only)
+    pub fn name_trump(&self, allow_no_trump: bool) -> Suit {
+        let no_trump: &[Suit] = if allow_no_trump {
+            &[Suit::NoTrump]
+        } else {
+            &[]
+        };
+        SUIT_VARIANTS
+            .iter()
+            .chain(no_trump)
+            .copied()
+            .max_by_key(|suit| hand_strength(&self.hand, *suit))
+            .unwrap_or(Suit::NoTrump)
+    }
+
+    // choose an off suit to call for a partner, one without the ace in hand and with the most
+    // cards for a partner to back up (bots only)
+    pub fn call_ace(&self, high_suit: Suit) -> Suit {
+        SUIT_VARIANTS
+            .iter()
+            .copied()
+            .filter(|suit| *suit != high_suit)
+            .max_by_key(|suit| {
+                let cards = self
+                    .hand
+                    .iter()
+                    .filter(|card| card.get_effective_suit(high_suit) == *suit);
+                let has_ace = cards.clone().any(|card| card.face == Face::Ace);
+                (!has_ace, cards.count())
+            })
+            .unwrap_or(Suit::_None)
+    }
+
+    // find the cards in hand that may be played to follow the lead suit
+    pub fn legal_card_idxs(&self, lead_suit: Suit, high_suit: Suit) -> Vec<usize> {
+        let following: Vec<usize> = (0..self.hand.len())
+            .filter(|idx| self.hand[*idx].get_effective_suit(high_suit) == lead_suit)
+            .collect();
+        if following.is_empty() {
+            // leading or unable to follow suit, anything goes
+            (0..self.hand.len()).collect()
+        } else {
+            following
+        }
+    }
+
+    // choose which card to play to the trick (bots only)
+    pub fn choose_card(
+        &self,
+        lead_suit: Suit,
+        high_suit: Suit,
+        winning_card: Option<Card>,
+        partner_winning: bool,
+    ) -> usize {
+        let legal_idxs = self.legal_card_idxs(lead_suit, high_suit);
+        let rank = |idx: &usize| {
+            let card = self.hand[*idx];
+            card.get_rank(high_suit, card.get_effective_suit(high_suit))
+        };
+        let lowest = legal_idxs.iter().min_by_key(|idx| rank(idx));
+        let chosen = match winning_card {
+            // lead with the strongest card in hand
+            None => legal_idxs.iter().max_by_key(|idx| rank(idx)),
+            // let partner take the trick
+            Some(_) if partner_winning => lowest,
+            // take the trick as cheaply as possible, or throw off the lowest card
+            Some(winning_card) => {
+                let winning_rank = winning_card.get_rank(high_suit, lead_suit);
+                legal_idxs
+                    .iter()
+                    .filter(|idx| self.hand[**idx].get_rank(high_suit, lead_suit) > winning_rank)
+                    .min_by_key(|idx| rank(idx))
+                    .or(lowest)
+            }
+        };
+        chosen.copied().unwrap_or_default()
+    }
+
+    // choose the strongest card that fails to follow suit, to try to steal the trick with a renege
+    // (bots only)
+    pub fn choose_renege(&self, lead_suit: Suit, high_suit: Suit) -> Option<usize> {
+        let legal_idxs = self.legal_card_idxs(lead_suit, high_suit);
+        (0..self.hand.len())
+            .filter(|idx| !legal_idxs.contains(idx))
+            .max_by_key(|idx| self.hand[*idx].get_rank(high_suit, lead_suit))
+    }
+
+    // remove a card from hand to be played
+    pub fn play_card(&mut self, card_idx: usize) -> Card {
+        self.hand.remove(card_idx)
+    }
+}
 
-    pub fn pickup_card() {}
+// nines and tens are the cards that make up a farmer's hand
+fn is_farmers_card(card: Card) -> bool {
+    card.face.get_rank() <= Face::Ten.get_rank()
+}
+
+// estimate the trick taking value of a single card with the given high suit
+fn card_strength(card: Card, high_suit: Suit) -> u8 {
+    if high_suit == Suit::NoTrump {
+        // without trump only the top of each suit is sure to take a trick
+        match card.face {
+            Face::Joker | Face::Ace => 6,
+            Face::King => 3,
+            _ => 0,
+        }
+    } else if card.get_effective_suit(high_suit) == high_suit {
+        // 1 for the seven of trump up to 10 for the joker
+        card.get_rank(high_suit, high_suit) - 22
+    } else if card.face == Face::Ace {
+        3
+    } else {
+        0
+    }
+}
 
-    pub fn call_suit() {}
+// estimate the trick taking value of a hand with the given high suit
+fn hand_strength(hand: &[Card], high_suit: Suit) -> u8 {
+    hand.iter()
+        .map(|card| card_strength(*card, high_suit))
+        .sum()
+}
 
-    pub fn play_card() {}
+// find the index of the lowest ranked card in a hand
+fn lowest_card_idx(hand: &[Card], high_suit: Suit) -> usize {
+    hand.iter()
+        .enumerate()
+        .min_by_key(|(_, card)| card.get_rank(high_suit, Suit::_None))
+        .map(|(idx, _)| idx)
+        .unwrap_or_default()
 }
@@ -0,0 +1,436 @@
+use crate::engine::{
+    card::{Face, EUCHRE_FACES},
+    table::{Seat, HEXAGON_SEAT_VARIANTS, SEAT_VARIANTS},
+};
+use std::fmt::{Display, Formatter};
+
+const WINNING_SCORE: u8 = 10;
+const RENEGE_PENALTY: u8 = 2;
+
+// game mode repr
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum GameMode {
+    #[default]
+    FourHanded,
+    Cutthroat,
+    // head to head from a 24 card deck with the rest set aside, neither player has a partner to
+    // go alone without, so hands score like a four-handed hand that isn't played alone
+    TwoHanded,
+    SixHanded,
+    Pepper,
+    Railroad,
+    CallAce,
+}
+
+impl GameMode {
+    pub fn next(&self) -> Self {
+        match self {
+            GameMode::FourHanded => GameMode::Cutthroat,
+            GameMode::Cutthroat => GameMode::TwoHanded,
+            GameMode::TwoHanded => GameMode::SixHanded,
+            GameMode::SixHanded => GameMode::Pepper,
+            GameMode::Pepper => GameMode::Railroad,
+            GameMode::Railroad => GameMode::CallAce,
+            GameMode::CallAce => GameMode::FourHanded,
+        }
+    }
+
+    // the seats in play, starting from the user and going left
+    pub fn seats(&self) -> Vec<Seat> {
+        match self {
+            GameMode::FourHanded | GameMode::Pepper | GameMode::Railroad | GameMode::CallAce => {
+                SEAT_VARIANTS.to_vec()
+            }
+            GameMode::Cutthroat => vec![Seat::Bottom, Seat::Left, Seat::Right],
+            GameMode::TwoHanded => vec![Seat::Bottom, Seat::Top],
+            GameMode::SixHanded => HEXAGON_SEAT_VARIANTS.to_vec(),
+        }
+    }
+
+    // the number of cards dealt to each player, which is also the number of tricks
+    pub fn hand_size(&self) -> usize {
+        match self {
+            GameMode::SixHanded => 4,
+            // pepper deals out the whole deck
+            GameMode::Pepper => 6,
+            _ => 5,
+        }
+    }
+
+    // whether players sit in partnerships, rather than each scoring for themselves
+    pub fn has_partners(&self) -> bool {
+        match self {
+            GameMode::FourHanded | GameMode::SixHanded | GameMode::Pepper | GameMode::Railroad => {
+                true
+            }
+            // call-ace partners change every hand, so everyone scores for themselves
+            GameMode::Cutthroat | GameMode::TwoHanded | GameMode::CallAce => false,
+        }
+    }
+}
+
+impl Display for GameMode {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            GameMode::FourHanded => write!(f, "Four-Handed"),
+            GameMode::Cutthroat => write!(f, "Cutthroat"),
+            GameMode::TwoHanded => write!(f, "Two-Handed"),
+            GameMode::SixHanded => write!(f, "Six-Handed"),
+            GameMode::Pepper => write!(f, "Pepper"),
+            GameMode::Railroad => write!(f, "Railroad"),
+            GameMode::CallAce => write!(f, "Call-Ace"),
+        }
+    }
+}
+
+// deck composition repr
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum DeckRule {
+    #[default]
+    TwentyFour,
+    TwentyEight,
+    ThirtyTwo,
+}
+
+impl DeckRule {
+    pub fn next(&self) -> Self {
+        match self {
+            DeckRule::TwentyFour => DeckRule::TwentyEight,
+            DeckRule::TwentyEight => DeckRule::ThirtyTwo,
+            DeckRule::ThirtyTwo => DeckRule::TwentyFour,
+        }
+    }
+
+    // the faces dealt in every suit, the bigger decks add eights and then sevens
+    pub fn faces(&self) -> Vec<Face> {
+        match self {
+            DeckRule::TwentyFour => EUCHRE_FACES.to_vec(),
+            DeckRule::TwentyEight => [&[Face::Eight], EUCHRE_FACES].concat(),
+            DeckRule::ThirtyTwo => [&[Face::Seven, Face::Eight], EUCHRE_FACES].concat(),
+        }
+    }
+}
+
+impl Display for DeckRule {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            DeckRule::TwentyFour => write!(f, "24 Cards"),
+            DeckRule::TwentyEight => write!(f, "28 Cards (Eights)"),
+            DeckRule::ThirtyTwo => write!(f, "32 Cards (Sevens)"),
+        }
+    }
+}
+
+// all pass rule repr
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum AllPassRule {
+    #[default]
+    Redeal,
+    StickTheDealer,
+}
+
+impl AllPassRule {
+    pub fn next(&self) -> Self {
+        match self {
+            AllPassRule::Redeal => AllPassRule::StickTheDealer,
+            AllPassRule::StickTheDealer => AllPassRule::Redeal,
+        }
+    }
+}
+
+impl Display for AllPassRule {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AllPassRule::Redeal => write!(f, "Redeal"),
+            AllPassRule::StickTheDealer => write!(f, "Stick the Dealer"),
+        }
+    }
+}
+
+// renege rule repr
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum RenegeRule {
+    #[default]
+    Prevent,
+    CallOut,
+}
+
+impl RenegeRule {
+    pub fn next(&self) -> Self {
+        match self {
+            RenegeRule::Prevent => RenegeRule::CallOut,
+            RenegeRule::CallOut => RenegeRule::Prevent,
+        }
+    }
+}
+
+impl Display for RenegeRule {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RenegeRule::Prevent => write!(f, "Prevent"),
+            RenegeRule::CallOut => write!(f, "Call Out"),
+        }
+    }
+}
+
+// decided hand rule repr
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ThrowInRule {
+    #[default]
+    PlayThrough,
+    ThrowIn,
+}
+
+impl ThrowInRule {
+    pub fn next(&self) -> Self {
+        match self {
+            ThrowInRule::PlayThrough => ThrowInRule::ThrowIn,
+            ThrowInRule::ThrowIn => ThrowInRule::PlayThrough,
+        }
+    }
+}
+
+impl Display for ThrowInRule {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ThrowInRule::PlayThrough => write!(f, "Play Through"),
+            ThrowInRule::ThrowIn => write!(f, "Throw Them In"),
+        }
+    }
+}
+
+// rule preset repr
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum RulePreset {
+    #[default]
+    MidwestStandard,
+    Tournament,
+    Custom,
+}
+
+impl RulePreset {
+    pub fn next(&self) -> Self {
+        match self {
+            RulePreset::MidwestStandard => RulePreset::Tournament,
+            RulePreset::Tournament => RulePreset::MidwestStandard,
+            RulePreset::Custom => RulePreset::MidwestStandard,
+        }
+    }
+
+    // get the house rules for the preset, custom rules start from the defaults
+    pub fn rules(&self) -> RuleSet {
+        match self {
+            RulePreset::MidwestStandard => RuleSet {
+                all_pass: AllPassRule::StickTheDealer,
+                farmers_hand: true,
+                throw_in: ThrowInRule::ThrowIn,
+                ..RuleSet::default()
+            },
+            RulePreset::Tournament => RuleSet {
+                renege: RenegeRule::CallOut,
+                ..RuleSet::default()
+            },
+            RulePreset::Custom => RuleSet::default(),
+        }
+    }
+}
+
+impl Display for RulePreset {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            RulePreset::MidwestStandard => write!(f, "Midwest Standard"),
+            RulePreset::Tournament => write!(f, "Tournament"),
+            RulePreset::Custom => write!(f, "Custom"),
+        }
+    }
+}
+
+// house rules repr
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleSet {
+    pub mode: GameMode,
+    pub all_pass: AllPassRule,
+    pub winning_score: u8,
+    pub loners_allowed: bool,
+    pub defend_alone: bool,
+    pub renege: RenegeRule,
+    pub renege_penalty: u8,
+    pub farmers_hand: bool,
+    pub ace_no_face: bool,
+    pub deck: DeckRule,
+    // play with the joker as the best bower
+    pub joker: bool,
+    // allow calling no trump in the second round
+    pub no_trump: bool,
+    pub throw_in: ThrowInRule,
+    pub auto_play: bool,
+}
+
+impl RuleSet {
+    // the deck in play, pepper deals out exactly 24 cards, two-handed strips a 32 card deck down to
+    // 24 and six players need the eights
+    pub fn uses_deck(&self) -> DeckRule {
+        match (self.mode, self.deck) {
+            (GameMode::Pepper | GameMode::TwoHanded, _) => DeckRule::TwentyFour,
+            (GameMode::SixHanded, DeckRule::TwentyFour) => DeckRule::TwentyEight,
+            (_, deck) => deck,
+        }
+    }
+
+    // the faces dealt in every suit
+    pub fn faces(&self) -> Vec<Face> {
+        self.uses_deck().faces()
+    }
+
+    // whether a farmer's hand may be swapped, the cards set aside in two-handed stay out of play
+    pub fn uses_farmers_hand(&self) -> bool {
+        self.farmers_hand && self.mode != GameMode::TwoHanded
+    }
+
+    // whether the joker is in the deck, pepper deals out every card and has no room for it
+    pub fn uses_joker(&self) -> bool {
+        match self.mode {
+            GameMode::Pepper => false,
+            GameMode::Railroad => true,
+            _ => self.joker,
+        }
+    }
+
+    // whether the maker may go alone, which takes a partner to send out, a call-ace loner plays
+    // without calling for a partner
+    pub fn uses_loners(&self) -> bool {
+        self.loners_allowed && (self.mode.has_partners() || self.mode == GameMode::CallAce)
+    }
+
+    // whether a defender may answer a loner by going alone, which takes two partnerships,
+    // railroad always allows it
+    pub fn uses_defend_alone(&self) -> bool {
+        let two_teams = matches!(
+            self.mode,
+            GameMode::FourHanded | GameMode::Pepper | GameMode::Railroad
+        );
+        two_teams && self.uses_loners() && (self.defend_alone || self.mode == GameMode::Railroad)
+    }
+
+    // find the preset these rules match, if any
+    pub fn preset(&self) -> RulePreset {
+        // the mode and auto-play are chosen separately from the house rules
+        [RulePreset::MidwestStandard, RulePreset::Tournament]
+            .into_iter()
+            .find(|preset| {
+                *self
+                    == RuleSet {
+                        mode: self.mode,
+                        auto_play: self.auto_play,
+                        ..preset.rules()
+                    }
+            })
+            .unwrap_or(RulePreset::Custom)
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet {
+            mode: GameMode::default(),
+            all_pass: AllPassRule::default(),
+            winning_score: WINNING_SCORE,
+            loners_allowed: true,
+            defend_alone: false,
+            renege: RenegeRule::default(),
+            renege_penalty: RENEGE_PENALTY,
+            farmers_hand: false,
+            ace_no_face: false,
+            deck: DeckRule::default(),
+            joker: false,
+            no_trump: false,
+            throw_in: ThrowInRule::default(),
+            auto_play: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_presets() {
+        assert!(RulePreset::MidwestStandard.rules().preset() == RulePreset::MidwestStandard);
+        assert!(RulePreset::Tournament.rules().preset() == RulePreset::Tournament);
+        let rules = RuleSet {
+            winning_score: 11,
+            ..RulePreset::Tournament.rules()
+        };
+        assert!(rules.preset() == RulePreset::Custom);
+    }
+
+    #[test]
+    fn test_uses_deck() {
+        let rules = RuleSet {
+            deck: DeckRule::ThirtyTwo,
+            ..RuleSet::default()
+        };
+        assert!(rules.faces().len() == 8);
+        let rules = RuleSet {
+            mode: GameMode::Pepper,
+            ..rules
+        };
+        assert!(rules.uses_deck() == DeckRule::TwentyFour);
+        let rules = RuleSet {
+            mode: GameMode::SixHanded,
+            deck: DeckRule::TwentyFour,
+            ..rules
+        };
+        assert!(rules.uses_deck() == DeckRule::TwentyEight);
+        let rules = RuleSet {
+            mode: GameMode::TwoHanded,
+            deck: DeckRule::ThirtyTwo,
+            ..rules
+        };
+        assert!(rules.uses_deck() == DeckRule::TwentyFour);
+    }
+
+    #[test]
+    fn test_uses_loners() {
+        let rules = RuleSet {
+            defend_alone: true,
+            ..RuleSet::default()
+        };
+        assert!(rules.uses_loners() && rules.uses_defend_alone());
+        // nobody has a partner to send out in cutthroat
+        let cutthroat = RuleSet {
+            mode: GameMode::Cutthroat,
+            ..rules.clone()
+        };
+        assert!(!cutthroat.uses_loners() && !cutthroat.uses_defend_alone());
+        // three partnerships leave no single defender to answer a loner
+        let six_handed = RuleSet {
+            mode: GameMode::SixHanded,
+            ..rules.clone()
+        };
+        assert!(six_handed.uses_loners() && !six_handed.uses_defend_alone());
+        let railroad = RuleSet {
+            mode: GameMode::Railroad,
+            defend_alone: false,
+            ..rules
+        };
+        assert!(railroad.uses_defend_alone());
+    }
+
+    #[test]
+    fn test_uses_joker() {
+        let pepper = RuleSet {
+            mode: GameMode::Pepper,
+            joker: true,
+            ..RuleSet::default()
+        };
+        assert!(!pepper.uses_joker());
+        let railroad = RuleSet {
+            mode: GameMode::Railroad,
+            joker: false,
+            ..RuleSet::default()
+        };
+        assert!(railroad.uses_joker());
+    }
+}
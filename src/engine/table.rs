@@ -1,41 +1,49 @@
-use rand::{
-    distributions::{Distribution, Standard},
-    Rng,
+use crate::engine::card::{Card, Suit};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
 };
-use std::fmt::{Display, Formatter};
 
 pub static SEAT_VARIANTS: &[Seat] = &[Seat::Bottom, Seat::Left, Seat::Top, Seat::Right];
 
-#[derive(Default, Clone, PartialEq, Eq, Hash)]
-pub enum Seat {
-    #[default]
-    Bottom,
-    Left,
-    Top,
-    Right,
+// seats around a six-handed table, going left from the user
+pub static HEXAGON_SEAT_VARIANTS: &[Seat] = &[
+    Seat::Bottom,
+    Seat::BottomLeft,
+    Seat::TopLeft,
+    Seat::Top,
+    Seat::TopRight,
+    Seat::BottomRight,
+];
+
+// bid repr, pepper outbids any number of tricks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Bid {
+    Tricks(u8),
+    // take every trick without a partner
+    Pepper,
 }
 
-impl Seat {
-    pub fn next(&self) -> Self {
+impl Display for Bid {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            Seat::Bottom => Seat::Left,
-            Seat::Left => Seat::Top,
-            Seat::Top => Seat::Right,
-            Seat::Right => Seat::Bottom,
+            Bid::Tricks(tricks) => write!(f, "{}", tricks),
+            Bid::Pepper => write!(f, "Pepper"),
         }
     }
 }
 
-impl Distribution<Seat> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Seat {
-        match rng.gen_range(0..4) {
-            0 => Seat::Bottom,
-            1 => Seat::Left,
-            2 => Seat::Top,
-            3 => Seat::Right,
-            _ => Seat::Bottom,
-        }
-    }
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub enum Seat {
+    #[default]
+    Bottom,
+    Left,
+    Top,
+    Right,
+    BottomLeft,
+    TopLeft,
+    TopRight,
+    BottomRight,
 }
 
 impl Display for Seat {
@@ -45,9 +53,252 @@ impl Display for Seat {
             Seat::Left => write!(f, "Left"),
             Seat::Top => write!(f, "Top"),
             Seat::Right => write!(f, "Right"),
+            Seat::BottomLeft => write!(f, "Bottom Left"),
+            Seat::TopLeft => write!(f, "Top Left"),
+            Seat::TopRight => write!(f, "Top Right"),
+            Seat::BottomRight => write!(f, "Bottom Right"),
+        }
+    }
+}
+
+// trick repr
+#[derive(Default, Clone)]
+pub struct Trick {
+    pub plays: Vec<(Seat, Card)>,
+    pub winner_seat: Option<Seat>,
+}
+
+impl Trick {
+    // the effective suit of the card that led the trick
+    pub fn lead_suit(&self, high_suit: Suit) -> Suit {
+        self.plays
+            .first()
+            .map(|(_, card)| card.get_effective_suit(high_suit))
+            .unwrap_or(Suit::_None)
+    }
+
+    // the play currently winning the trick
+    pub fn winning_play(&self, high_suit: Suit) -> Option<&(Seat, Card)> {
+        let lead_suit = self.lead_suit(high_suit);
+        self.plays
+            .iter()
+            .max_by_key(|(_, card)| card.get_rank(high_suit, lead_suit))
+    }
+}
+
+// table repr
+#[derive(Default)]
+pub struct Table {
+    pub dealer_seat: Seat,
+    pub maker_seat: Option<Seat>,
+    pub bid: Option<Bid>,
+    // the suit called for a secret partner and the card that makes them one
+    pub partner_suit: Option<Suit>,
+    pub partner_card: Option<Card>,
+    pub partner_seat: Option<Seat>,
+    pub partner_revealed: bool,
+    pub alone_seat: Option<Seat>,
+    pub defender_alone_seat: Option<Seat>,
+    pub sitting_out_seats: Vec<Seat>,
+    pub leader_seat: Seat,
+    pub first_dealer_cards: Vec<(Seat, Card)>,
+    pub kitty: Vec<Card>,
+    // discards, the turned down upcard and folded hands, out of play for the rest of the hand
+    pub buried: Vec<Card>,
+    pub upcard: Option<Card>,
+    pub starting_hands: HashMap<Seat, Vec<Card>>,
+    pub trick: Trick,
+    pub tricks: Vec<Trick>,
+    pub renege_seat: Option<Seat>,
+    pub thrown_in: bool,
+}
+
+impl Table {
+    // lay out the undealt cards as the kitty and turn up its top card
+    pub fn set_kitty(&mut self, mut cards: Vec<Card>) {
+        self.upcard = cards.pop();
+        self.kitty = cards;
+    }
+
+    // bury a card face down, apart from the kitty
+    pub fn bury(&mut self, card: Card) {
+        self.buried.push(card);
+    }
+
+    // exchange cards from a hand for as many face down kitty cards
+    pub fn swap_kitty(&mut self, cards: Vec<Card>) -> Vec<Card> {
+        let kitty_cards = self.kitty.split_off(self.kitty.len() - cards.len());
+        self.kitty.extend(cards);
+        kitty_cards
+    }
+
+    // turn the upcard face down and bury it
+    pub fn turn_down(&mut self) -> Option<Card> {
+        let upcard = self.upcard.take()?;
+        self.bury(upcard);
+        Some(upcard)
+    }
+
+    // play a card from the given seat to the current trick
+    pub fn play(&mut self, seat: Seat, card: Card) {
+        self.trick.plays.push((seat, card));
+    }
+
+    // move the completed trick off the felt and into the trick history
+    pub fn collect_trick(&mut self) {
+        let trick = std::mem::take(&mut self.trick);
+        self.tricks.push(trick);
+    }
+
+    /// Find every player who failed to follow suit while holding the lead suit
+    ///
+    /// The hand is replayed from the starting hands through each trick played so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `high_suit` - The high suit of the hand
+    ///
+    /// # Returns
+    ///
+    /// The seats of the players who reneged, in the order they reneged
+    pub fn find_reneges(&self, high_suit: Suit) -> Vec<Seat> {
+        let mut hands = self.starting_hands.clone();
+        let mut reneges = vec![];
+        for trick in self.tricks.iter().chain([&self.trick]) {
+            let lead_suit = trick.lead_suit(high_suit);
+            for (seat, card) in trick.plays.iter() {
+                let Some(hand) = hands.get_mut(seat) else {
+                    continue;
+                };
+                let could_follow = hand
+                    .iter()
+                    .any(|held| held.get_effective_suit(high_suit) == lead_suit);
+                if could_follow
+                    && card.get_effective_suit(high_suit) != lead_suit
+                    && !reneges.contains(seat)
+                {
+                    reneges.push(seat.clone());
+                }
+                hand.retain(|held| held != card);
+            }
         }
+        reneges
+    }
+
+    // clear the felt for the next hand, the dealer stays put
+    pub fn clear(&mut self) {
+        self.maker_seat = None;
+        self.bid = None;
+        self.partner_suit = None;
+        self.partner_card = None;
+        self.partner_seat = None;
+        self.partner_revealed = false;
+        self.alone_seat = None;
+        self.defender_alone_seat = None;
+        self.sitting_out_seats.clear();
+        self.kitty.clear();
+        self.buried.clear();
+        self.upcard = None;
+        self.starting_hands.clear();
+        self.trick = Trick::default();
+        self.tricks.clear();
+        self.renege_seat = None;
+        self.thrown_in = false;
     }
 }
 
-// TODO: implement table struct that contains played cards, deck, kitty, etc
-pub struct Table {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::card::Face;
+
+    fn card(face: Face, suit: Suit) -> Card {
+        Card { face, suit }
+    }
+
+    #[test]
+    fn test_find_reneges() {
+        let high_suit = Suit::Spades;
+        let mut table = Table {
+            starting_hands: HashMap::from([
+                (
+                    Seat::Bottom,
+                    vec![
+                        card(Face::Ace, Suit::Hearts),
+                        card(Face::Nine, Suit::Hearts),
+                    ],
+                ),
+                (
+                    Seat::Left,
+                    vec![
+                        card(Face::Jack, Suit::Clubs),
+                        card(Face::King, Suit::Hearts),
+                    ],
+                ),
+                (
+                    Seat::Top,
+                    vec![
+                        card(Face::Ten, Suit::Diamonds),
+                        card(Face::Queen, Suit::Diamonds),
+                    ],
+                ),
+                (
+                    Seat::Right,
+                    vec![
+                        card(Face::Nine, Suit::Spades),
+                        card(Face::Ten, Suit::Hearts),
+                    ],
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        // left bower led as trump, right holds a spade but follows with a heart
+        table.play(Seat::Left, card(Face::Jack, Suit::Clubs));
+        table.play(Seat::Top, card(Face::Ten, Suit::Diamonds));
+        table.play(Seat::Right, card(Face::Ten, Suit::Hearts));
+        table.play(Seat::Bottom, card(Face::Nine, Suit::Hearts));
+        assert!(table.find_reneges(high_suit) == vec![Seat::Right]);
+        table.collect_trick();
+
+        // no spades left for anyone, so nothing new is a renege
+        table.play(Seat::Left, card(Face::King, Suit::Hearts));
+        table.play(Seat::Top, card(Face::Queen, Suit::Diamonds));
+        table.play(Seat::Right, card(Face::Nine, Suit::Spades));
+        table.play(Seat::Bottom, card(Face::Ace, Suit::Hearts));
+        assert!(table.find_reneges(high_suit) == vec![Seat::Right]);
+
+        // without the left bower as trump, nobody reneged
+        assert!(table.find_reneges(Suit::Hearts).is_empty());
+    }
+
+    #[test]
+    fn test_swap_kitty() {
+        let mut table = Table::default();
+        table.set_kitty(vec![
+            card(Face::Ace, Suit::Hearts),
+            card(Face::King, Suit::Hearts),
+            card(Face::Jack, Suit::Clubs),
+            card(Face::Queen, Suit::Spades),
+        ]);
+        let cards = table.swap_kitty(vec![
+            card(Face::Nine, Suit::Hearts),
+            card(Face::Ten, Suit::Clubs),
+        ]);
+        assert!(
+            cards
+                == vec![
+                    card(Face::King, Suit::Hearts),
+                    card(Face::Jack, Suit::Clubs)
+                ]
+        );
+        assert!(table.kitty.len() == 3);
+        assert!(table.upcard == Some(card(Face::Queen, Suit::Spades)));
+
+        // the turned down upcard is buried and the kitty keeps only the undealt cards
+        table.turn_down();
+        assert!(table.upcard.is_none());
+        assert!(table.kitty.len() == 3);
+        assert!(table.buried == vec![card(Face::Queen, Suit::Spades)]);
+    }
+}
@@ -5,7 +5,8 @@ use std::collections::HashSet;
 pub struct Team {
     pub name: String,
     pub seats: HashSet<Seat>,
-    pub game_score: u8,
+    // a team set back for missing a bid can go below zero
+    pub game_score: i16,
     pub hand_score: u8,
 }
 
@@ -10,22 +10,29 @@ pub const VERTICAL_CARD_RIGHT_EDGE: [&str; 7] = ["╮", "│", "│", "│", "
 // TODO: figure out how to make these dynamic based on the screen size
 pub const CARD_WIDTH: usize = 9;
 pub const CARD_HEIGHT: usize = 7;
+pub const SMALL_CARD_WIDTH: usize = 4;
 
 // pub fn horizontal_cards(cards: Vec<Card>, splayed: bool, show_values: bool) {}
 
 // pub fn vertical_cards(show_values: bool) {}
 
 pub fn card_area(card: Card) -> Vec<String> {
-    let card_area = vec![
-        "─".repeat(CARD_WIDTH),
-        format!(" {}{}", card.face.get_symbol(), " ".repeat(CARD_WIDTH - 2)),
-        " ".repeat(CARD_WIDTH),
+    // the joker has no suit to show, so it gets its name across the middle
+    let center = if card.is_joker() {
+        format!("{:^width$}", "JOKER", width = CARD_WIDTH)
+    } else {
         format!(
             "{}{}{}",
             " ".repeat(CARD_WIDTH / 2),
             card.suit.get_symbol(),
             " ".repeat(CARD_WIDTH / 2),
-        ),
+        )
+    };
+    let card_area = vec![
+        "─".repeat(CARD_WIDTH),
+        format!(" {}{}", card.face.get_symbol(), " ".repeat(CARD_WIDTH - 2)),
+        " ".repeat(CARD_WIDTH),
+        center,
         " ".repeat(CARD_WIDTH),
         format!("{}{} ", " ".repeat(CARD_WIDTH - 2), card.face.get_symbol()),
         "─".repeat(CARD_WIDTH),
@@ -33,23 +40,70 @@ pub fn card_area(card: Card) -> Vec<String> {
     card_area
 }
 
-pub fn bottom_player_cards(cards: Vec<Card>) -> Paragraph<'static> {
+pub fn bottom_player_cards(cards: Vec<Card>, numbered: bool) -> Paragraph<'static> {
     let mut card_areas: Vec<Vec<String>> = vec![];
     for card in cards {
         card_areas.push(card_area(card));
     }
 
-    let mut hand_area = vec!["".to_string(); CARD_HEIGHT];
-    for card_area in card_areas.iter() {
+    let mut hand_area = vec!["".to_string(); CARD_HEIGHT + 1];
+    for (card_idx, card_area) in card_areas.iter().enumerate() {
         for (idx, line) in card_area.iter().enumerate() {
             hand_area[idx].push_str(VERTICAL_CARD_LEFT_EDGE[idx]);
             hand_area[idx].push_str(line);
             hand_area[idx].push_str(VERTICAL_CARD_RIGHT_EDGE[idx]);
         }
+        // card number used to select the card
+        if !numbered {
+            continue;
+        }
+        hand_area[CARD_HEIGHT].push_str(&format!(
+            "{:>width$}",
+            format!("{}.", card_idx + 1),
+            width = CARD_WIDTH + 1
+        ));
+        hand_area[CARD_HEIGHT].push(' ');
     }
     string_vec_to_paragraph(hand_area)
 }
 
+// render small cards side by side, face down where no card is given
+pub fn small_cards(cards: Vec<Option<Card>>) -> Paragraph<'static> {
+    let mut cards_area = vec!["".to_string(); 3];
+    for card in cards {
+        for (line, card_line) in cards_area.iter_mut().zip(small_card_lines(card)) {
+            line.push_str(&card_line);
+        }
+    }
+    string_vec_to_paragraph(cards_area)
+}
+
+fn small_card_lines(card: Option<Card>) -> [String; 3] {
+    let face = match card {
+        Some(card) if card.is_joker() => "│ JK │".to_string(),
+        Some(card) => format!("│ {}{} │", card.face.get_symbol(), card.suit.get_symbol()),
+        None => format!("│{}│", "░".repeat(SMALL_CARD_WIDTH)),
+    };
+    [
+        format!("╭{}╮", "─".repeat(SMALL_CARD_WIDTH)),
+        face,
+        format!("╰{}╯", "─".repeat(SMALL_CARD_WIDTH)),
+    ]
+}
+
+// render the face down kitty with its size, which depends on the deck and the number of players,
+// next to the upcard if there is one
+pub fn kitty_cards(num_cards: usize, upcard: Option<Card>) -> Paragraph<'static> {
+    let mut kitty_area = small_card_lines(None);
+    kitty_area[1] = format!("│{:░^width$}│", num_cards, width = SMALL_CARD_WIDTH);
+    if upcard.is_some() {
+        for (line, upcard_line) in kitty_area.iter_mut().zip(small_card_lines(upcard)) {
+            line.push_str(&upcard_line);
+        }
+    }
+    string_vec_to_paragraph(kitty_area.to_vec())
+}
+
 // pub fn top_player_cards(cards: Vec<Card>) -> Paragraph<'static> {
 //     string_vec_to_paragraph(cards.into_iter().map(card_area).flatten().collect())
 // }
@@ -3,6 +3,7 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
+use std::collections::HashMap;
 
 pub struct PlayerLayout {
     pub name_area: Rect,
@@ -47,24 +48,164 @@ impl ScoreBoardLayout {
             hand_score_area: layout_base[3],
         }
     }
+
+    // a score board without the spacer above it
+    fn compact(rect: Rect) -> Self {
+        let layout_base = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(rect);
+        Self {
+            team_name_area: layout_base[0],
+            game_score_area: layout_base[1],
+            hand_score_area: layout_base[2],
+        }
+    }
+
+    // stack a score board for each team, one under the other, dropping the spacers when they
+    // don't all fit
+    pub fn stacked(rect: Rect, num_teams: usize) -> Vec<Self> {
+        let height = (rect.height / num_teams.max(1) as u16).min(4);
+        let layout_base = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(height)].repeat(num_teams))
+            .split(rect);
+        layout_base
+            .iter()
+            .map(|rect| {
+                if height < 4 {
+                    Self::compact(*rect)
+                } else {
+                    Self::new(*rect)
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct TableLayout {
+    pub card_areas: HashMap<Seat, Rect>,
+    pub center_area: Rect,
 }
 
-// TODO: implement GameTableLayout
+impl TableLayout {
+    // lay out a spot for each seat's card around the kitty in the middle
+    pub fn new(rect: Rect, seats: &[Seat]) -> Self {
+        match seats.len() {
+            6 => Self::six_seat(rect),
+            _ => Self::four_seat(rect),
+        }
+    }
+
+    fn four_seat(rect: Rect) -> Self {
+        let layout_base = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Fill(1),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+            ])
+            .split(rect);
+        let layout_top = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 1, 1]))
+            .split(layout_base[1]);
+        let layout_mid = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 1, 1]))
+            .split(layout_base[2]);
+        let layout_bot = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 1, 1]))
+            .split(layout_base[3]);
+        Self {
+            card_areas: HashMap::from([
+                (Seat::Top, layout_top[1]),
+                (Seat::Left, layout_mid[0]),
+                (Seat::Right, layout_mid[2]),
+                (Seat::Bottom, layout_bot[1]),
+            ]),
+            center_area: layout_mid[1],
+        }
+    }
+
+    // the side seats sit above and below the kitty, like the points of a hexagon
+    fn six_seat(rect: Rect) -> Self {
+        let layout_base = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Fill(1),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+            ])
+            .split(rect);
+        let rows: Vec<_> = layout_base[1..6]
+            .iter()
+            .map(|row| {
+                Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints(Constraint::from_fills([1, 1, 1]))
+                    .split(*row)
+            })
+            .collect();
+        Self {
+            card_areas: HashMap::from([
+                (Seat::Top, rows[0][1]),
+                (Seat::TopLeft, rows[1][0]),
+                (Seat::TopRight, rows[1][2]),
+                (Seat::BottomLeft, rows[3][0]),
+                (Seat::BottomRight, rows[3][2]),
+                (Seat::Bottom, rows[4][1]),
+            ]),
+            center_area: rows[2][1],
+        }
+    }
+
+    pub fn get_card_area_by_seat(&self, seat: Seat) -> Rect {
+        *self.card_areas.get(&seat).expect("seat not in layout")
+    }
+}
 
 pub struct GameLayout {
     pub left_score_area: ScoreBoardLayout,
-    pub right_score_area: ScoreBoardLayout,
-    pub top_player_area: PlayerLayout,
-    pub left_player_area: PlayerLayout,
-    pub right_player_area: PlayerLayout,
-    pub bottom_player_area: PlayerLayout,
+    pub right_score_areas: Vec<ScoreBoardLayout>,
+    pub player_areas: HashMap<Seat, PlayerLayout>,
     pub table_area: Rect,
     pub msg_input_area: Rect,
     pub debug_area: Rect,
 }
 
 impl GameLayout {
-    pub fn new(frame: &mut Frame) -> Self {
+    // lay out the table for the seats in play, with a score board for each opposing team
+    pub fn new(frame: &mut Frame, seats: &[Seat], num_opp_teams: usize) -> Self {
+        match seats.len() {
+            2 => Self::two_seat(frame, num_opp_teams),
+            3 => Self::three_seat(frame, num_opp_teams),
+            6 => Self::six_seat(frame, num_opp_teams),
+            _ => Self::four_seat(frame, num_opp_teams),
+        }
+    }
+
+    // head to head across the table, leaving the side seats empty
+    fn two_seat(frame: &mut Frame, num_opp_teams: usize) -> Self {
+        let mut layout = Self::four_seat(frame, num_opp_teams);
+        layout
+            .player_areas
+            .retain(|seat, _| matches!(seat, Seat::Top | Seat::Bottom));
+        layout
+    }
+
+    fn four_seat(frame: &mut Frame, num_opp_teams: usize) -> Self {
         let layout_base = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -82,36 +223,115 @@ impl GameLayout {
             .direction(Direction::Horizontal)
             .constraints(Constraint::from_fills([1, 2, 1]))
             .split(layout_base[1]);
+        // the user's hand gets extra room for the six cards dealt in pepper
         let layout_bot = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints(Constraint::from_fills([1, 2, 1]))
+            .constraints(Constraint::from_fills([1, 3, 1]))
             .split(layout_base[2]);
         Self {
             left_score_area: ScoreBoardLayout::new(layout_top[0]),
-            right_score_area: ScoreBoardLayout::new(layout_top[2]),
-            top_player_area: PlayerLayout::new(layout_top[1]),
-            left_player_area: PlayerLayout::new(layout_mid[0]),
-            right_player_area: PlayerLayout::new(layout_mid[2]),
-            bottom_player_area: PlayerLayout::new(layout_bot[1]),
+            right_score_areas: ScoreBoardLayout::stacked(layout_top[2], num_opp_teams),
+            player_areas: HashMap::from([
+                (Seat::Top, PlayerLayout::new(layout_top[1])),
+                (Seat::Left, PlayerLayout::new(layout_mid[0])),
+                (Seat::Right, PlayerLayout::new(layout_mid[2])),
+                (Seat::Bottom, PlayerLayout::new(layout_bot[1])),
+            ]),
             table_area: layout_mid[1],
             msg_input_area: layout_base[3],
             debug_area: layout_bot[2],
         }
     }
 
-    pub fn get_player_area_by_seat(&self, seat: Seat) -> &PlayerLayout {
-        match seat {
-            Seat::Bottom => &self.bottom_player_area,
-            Seat::Left => &self.left_player_area,
-            Seat::Top => &self.top_player_area,
-            Seat::Right => &self.right_player_area,
+    // with nobody across the table, the side players and the table fill the top of the screen
+    fn three_seat(frame: &mut Frame, num_opp_teams: usize) -> Self {
+        let layout_base = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Fill(7),
+                Constraint::Fill(3),
+                Constraint::Length(3),
+            ])
+            .split(frame.size());
+        let layout_top = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 2, 1]))
+            .split(layout_base[0]);
+        let layout_left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(Constraint::from_fills([3, 4]))
+            .split(layout_top[0]);
+        let layout_right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(Constraint::from_fills([3, 4]))
+            .split(layout_top[2]);
+        let layout_bot = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 2, 1]))
+            .split(layout_base[1]);
+        Self {
+            left_score_area: ScoreBoardLayout::new(layout_left[0]),
+            right_score_areas: ScoreBoardLayout::stacked(layout_right[0], num_opp_teams),
+            player_areas: HashMap::from([
+                (Seat::Left, PlayerLayout::new(layout_left[1])),
+                (Seat::Right, PlayerLayout::new(layout_right[1])),
+                (Seat::Bottom, PlayerLayout::new(layout_bot[1])),
+            ]),
+            table_area: layout_top[1],
+            msg_input_area: layout_base[2],
+            debug_area: layout_bot[2],
         }
     }
 
-    pub fn get_score_area_by_seat(&self, seat: Seat) -> &ScoreBoardLayout {
-        match seat {
-            Seat::Bottom | Seat::Top => &self.left_score_area,
-            Seat::Left | Seat::Right => &self.right_score_area,
+    // a hexagon, with two players down each side of the table
+    fn six_seat(frame: &mut Frame, num_opp_teams: usize) -> Self {
+        let layout_base = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Fill(2),
+                Constraint::Fill(6),
+                Constraint::Fill(2),
+                Constraint::Length(3),
+            ])
+            .split(frame.size());
+        let layout_top = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 2, 1]))
+            .split(layout_base[0]);
+        let layout_mid = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 2, 1]))
+            .split(layout_base[1]);
+        let layout_left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(Constraint::from_fills([1, 1]))
+            .split(layout_mid[0]);
+        let layout_right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(Constraint::from_fills([1, 1]))
+            .split(layout_mid[2]);
+        let layout_bot = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 2, 1]))
+            .split(layout_base[2]);
+        Self {
+            left_score_area: ScoreBoardLayout::new(layout_top[0]),
+            right_score_areas: ScoreBoardLayout::stacked(layout_top[2], num_opp_teams),
+            player_areas: HashMap::from([
+                (Seat::Top, PlayerLayout::new(layout_top[1])),
+                (Seat::TopLeft, PlayerLayout::new(layout_left[0])),
+                (Seat::BottomLeft, PlayerLayout::new(layout_left[1])),
+                (Seat::TopRight, PlayerLayout::new(layout_right[0])),
+                (Seat::BottomRight, PlayerLayout::new(layout_right[1])),
+                (Seat::Bottom, PlayerLayout::new(layout_bot[1])),
+            ]),
+            table_area: layout_mid[1],
+            msg_input_area: layout_base[3],
+            debug_area: layout_bot[2],
         }
     }
+
+    pub fn get_player_area_by_seat(&self, seat: Seat) -> &PlayerLayout {
+        self.player_areas.get(&seat).expect("seat not in layout")
+    }
 }
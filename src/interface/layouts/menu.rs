@@ -12,6 +12,11 @@ pub struct MenuLayout {
 
 impl MenuLayout {
     pub fn new(frame: &mut Frame, num_menu_options: usize) -> Self {
+        Self::with_option_heights(frame, &[3].repeat(num_menu_options))
+    }
+
+    // build a menu whose options are not all the same height
+    pub fn with_option_heights(frame: &mut Frame, menu_option_heights: &[u16]) -> Self {
         let layout_base = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -34,7 +39,11 @@ impl MenuLayout {
             .split(layout_bottom[1]);
         let layout_menu_options = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Length(3)].repeat(num_menu_options))
+            .constraints(
+                menu_option_heights
+                    .iter()
+                    .map(|height| Constraint::Length(*height)),
+            )
             .split(layout_menu[1]);
         Self {
             header_area: layout_base[1],
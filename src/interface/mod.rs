@@ -32,9 +32,9 @@ pub struct Interface {
 
 impl Interface {
     // create a new interface instance
-    pub fn new() -> Self {
+    pub fn new(seed: Option<u64>) -> Self {
         let splash_screen = SplashScreen::new();
-        let setup_screen = SetupScreen::new();
+        let setup_screen = SetupScreen::new(seed);
         let game_screen = GameScreen::new();
         Self {
             state: InterfaceState::default(),
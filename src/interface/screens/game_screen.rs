@@ -1,31 +1,46 @@
 use crate::interface::{
-    components::popups::centered_popup_area, interface_callback::InterfaceCallback,
-    layouts::game::GameLayout, screens::Screen,
+    components::popups::centered_popup_area,
+    interface_callback::InterfaceCallback,
+    layouts::game::{GameLayout, ScoreBoardLayout, TableLayout},
+    screens::Screen,
 };
 use crate::{
     engine::{
-        card::Card,
-        game::Game,
+        action::GameAction,
+        card::{Card, Suit, SUIT_VARIANTS},
+        game::{Game, GameState},
         player::Player,
-        table::{Seat, SEAT_VARIANTS},
+        rules::RenegeRule,
+        table::{Bid, Seat},
+        team::Team,
+    },
+    interface::components::cards::{
+        bottom_player_cards, kitty_cards, small_cards, SMALL_CARD_WIDTH,
     },
-    interface::components::cards::bottom_player_cards,
 };
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
 use ratatui::{
-    layout::Alignment,
+    layout::{Alignment, Constraint, Direction, Layout},
     text::{Line, Text},
     widgets::{block::Block, BorderType, Borders, Clear, Paragraph},
     Frame,
 };
 use std::io::Result;
 
+// number of ticks to wait between automated actions
+const DEAL_DELAY_TICKS: u64 = 3;
+const BOT_DELAY_TICKS: u64 = 4;
+const PICK_DELAY_TICKS: u64 = 2;
+const TRICK_DELAY_TICKS: u64 = 8;
+
 // game screen repr
 #[derive(Default)]
 pub struct GameScreen {
     game: Game,
     is_paused: bool,
     tick_count: u64,
+    // why the user's last action was rejected
+    notice: Option<String>,
 }
 
 impl GameScreen {
@@ -34,70 +49,104 @@ impl GameScreen {
             game: Game::default(),
             is_paused: false,
             tick_count: 0,
+            notice: None,
         }
     }
 
     pub fn set_game(&mut self, game: Game) {
         self.game = game;
     }
+
+    // the user always sits at the bottom of the table
+    fn apply_user_action(&mut self, action: GameAction) {
+        self.notice = match self.game.apply(Seat::Bottom, action) {
+            Ok(_) => None,
+            Err(err) => Some(err.to_string()),
+        };
+    }
 }
 
 impl Screen for GameScreen {
     // render the game screen to the frame
     fn render(&mut self, frame: &mut Frame) -> Result<()> {
-        let game_layout = GameLayout::new(frame);
-        // TODO: make reusable components for score boards and player areas
+        let (user_teams, opp_teams): (Vec<&Team>, Vec<&Team>) = self
+            .game
+            .teams
+            .iter()
+            .partition(|team| team.seats.contains(&Seat::Bottom));
+        let game_layout = GameLayout::new(frame, &self.game.seats, opp_teams.len());
+        // TODO: make reusable components for player areas
 
-        // render score boards
-        frame.render_widget(
-            Paragraph::new(self.game.user_team.name.clone()).alignment(Alignment::Center),
-            game_layout.left_score_area.team_name_area,
-        );
-        frame.render_widget(
-            Paragraph::new(self.game.user_team.game_score.to_string()).alignment(Alignment::Center),
-            game_layout.left_score_area.game_score_area,
-        );
-        frame.render_widget(
-            Paragraph::new(self.game.user_team.hand_score.to_string()).alignment(Alignment::Center),
-            game_layout.left_score_area.hand_score_area,
-        );
-        frame.render_widget(
-            Paragraph::new(self.game.opp_team.name.clone()).alignment(Alignment::Center),
-            game_layout.right_score_area.team_name_area,
-        );
-        frame.render_widget(
-            Paragraph::new(self.game.opp_team.game_score.to_string()).alignment(Alignment::Center),
-            game_layout.right_score_area.game_score_area,
-        );
-        frame.render_widget(
-            Paragraph::new(self.game.user_team.hand_score.to_string()).alignment(Alignment::Center),
-            game_layout.right_score_area.hand_score_area,
-        );
+        // render score boards, the user's team on the left and everyone else on the right
+        for team in user_teams {
+            render_score_board(frame, team, &game_layout.left_score_area);
+        }
+        for (team, score_area) in opp_teams.iter().zip(game_layout.right_score_areas.iter()) {
+            render_score_board(frame, team, score_area);
+        }
 
         // render player areas
-        for seat in SEAT_VARIANTS {
+        for seat in self.game.seats.iter() {
             let player: &Player = self.game.get_player_in_seat(seat.clone());
             let player_area = game_layout.get_player_area_by_seat(seat.clone());
-            frame.render_widget(Paragraph::new(player.name.as_str()), player_area.name_area);
+            frame.render_widget(
+                Paragraph::new(player_name_line(&self.game, seat.clone())),
+                player_area.name_area,
+            );
+            // show the cards dealt face up while picking the first dealer
+            let (cards, numbered) = match self.game.state {
+                GameState::PickingDealer => (
+                    self.game
+                        .table
+                        .first_dealer_cards
+                        .iter()
+                        .filter(|(card_seat, _)| card_seat == seat)
+                        .map(|(_, card)| *card)
+                        .collect(),
+                    false,
+                ),
+                _ => (player.hand.clone(), true),
+            };
             // TODO: once all the card rendering logic is sorted out, this will be fully dynamic
             match seat {
                 Seat::Bottom => {
-                    frame.render_widget(
-                        bottom_player_cards(player.hand.clone()),
-                        game_layout.bottom_player_area.hand_area,
-                    );
+                    frame
+                        .render_widget(bottom_player_cards(cards, numbered), player_area.hand_area);
+                }
+                // dealt bot hands stay face down, a called partner would show in them otherwise
+                _ if numbered => {
+                    frame.render_widget(build_card_back_lines(cards.len()), player_area.hand_area);
                 }
                 _ => {
-                    frame.render_widget(build_card_lines(&player.hand), player_area.hand_area);
+                    frame.render_widget(build_card_lines(&cards), player_area.hand_area);
                 }
             }
         }
 
         // render table area
-        frame.render_widget(
-            Block::new().borders(Borders::ALL).title("Table"),
-            game_layout.table_area,
-        );
+        // make a no trump hand obvious, since there is no suit symbol to look for
+        let table_title = if self.game.high_suit == Suit::NoTrump {
+            "Table (No Trump)"
+        } else {
+            "Table"
+        };
+        let table_block = Block::new().borders(Borders::ALL).title(table_title);
+        let table_area = table_block.inner(game_layout.table_area);
+        frame.render_widget(table_block, game_layout.table_area);
+        let table_layout = TableLayout::new(table_area, &self.game.seats);
+        let table = &self.game.table;
+        if !table.kitty.is_empty() {
+            frame.render_widget(
+                kitty_cards(table.kitty.len(), table.upcard),
+                table_layout.center_area,
+            );
+        }
+        for (seat, card) in table.trick.plays.iter() {
+            frame.render_widget(
+                small_cards(vec![Some(*card)]),
+                table_layout.get_card_area_by_seat(seat.clone()),
+            );
+        }
 
         // TODO: eventually remove debug area or hide behind cli flag
         // render debug area
@@ -116,17 +165,30 @@ impl Screen for GameScreen {
                     "Current Player Name: ".to_string() + self.game.current_player().name.as_str(),
                 ),
                 Line::from(
-                    "Dealer Seat: ".to_string() + self.game.dealer_seat.to_string().as_str(),
+                    "Dealer Seat: ".to_string() + self.game.table.dealer_seat.to_string().as_str(),
                 ),
             ]),
             debug_area,
         );
 
         // io area
-        frame.render_widget(
-            Block::new().borders(Borders::ALL).title("Input/Msg Block"),
-            game_layout.msg_input_area,
-        );
+        let msg_input_block = Block::new().borders(Borders::ALL).title("Input/Msg Block");
+        let msg_input_area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(Constraint::from_fills([1, 1]))
+            .split(msg_input_block.inner(game_layout.msg_input_area));
+        frame.render_widget(msg_input_block, game_layout.msg_input_area);
+        let message = self
+            .notice
+            .clone()
+            .unwrap_or_else(|| self.game.latest_message().to_string());
+        frame.render_widget(Paragraph::new(message), msg_input_area[0]);
+        if let Some(prompt) = user_prompt(&self.game) {
+            frame.render_widget(
+                Paragraph::new(prompt).alignment(Alignment::Right),
+                msg_input_area[1],
+            );
+        }
 
         // pause menu
         if self.is_paused {
@@ -138,33 +200,364 @@ impl Screen for GameScreen {
             let menu_area = block.inner(menu_container);
             frame.render_widget(Clear, menu_container);
             frame.render_widget(block, menu_container);
-            frame.render_widget(Paragraph::new("Options coming soon..."), menu_area);
+            frame.render_widget(
+                Text::from(vec![
+                    Line::from(format!("Mode: {}", self.game.rules.mode)),
+                    Line::from(format!("Rules: {}", self.game.rules.preset())),
+                    Line::from(format!("Seed: {}", self.game.seed)),
+                    Line::from(""),
+                    Line::from("Options coming soon..."),
+                ]),
+                menu_area,
+            );
         }
         Ok(())
     }
 
     fn handle_key_event(&mut self, key_event: KeyEvent) -> Option<InterfaceCallback> {
         if key_event.kind == KeyEventKind::Press {
-            #[allow(clippy::match_single_binding)] // this will be populated in the future
             match key_event.code {
                 KeyCode::Char('p') | KeyCode::Char('P') => {
                     self.is_paused = !self.is_paused;
                 }
-                _ => {}
+                KeyCode::Enter if matches!(self.game.state, GameState::GameOver) => {
+                    return Some(InterfaceCallback::QuitToSplash);
+                }
+                KeyCode::Char('r') | KeyCode::Char('R')
+                    if !self.is_paused
+                        && self.game.rules.renege == RenegeRule::CallOut
+                        && matches!(self.game.state, GameState::PlayingHand) =>
+                {
+                    self.apply_user_action(GameAction::CallRenege);
+                }
+                _ if self.is_paused || !self.game.is_awaiting_user() => {}
+                // user decisions
+                code => {
+                    if let Some(action) = user_action_from_key(&self.game, code) {
+                        self.apply_user_action(action);
+                    }
+                }
             }
         }
         None
     }
 
     fn handle_tick_event(&mut self) -> Option<InterfaceCallback> {
-        if !self.is_paused {
-            self.tick_count = self.game.handle_game_tick(self.tick_count);
+        // hold the game while paused or until the user makes their decision
+        if self.is_paused || self.game.is_awaiting_user() {
+            return None;
+        }
+        if self.tick_count < step_delay_ticks(&self.game) {
+            self.tick_count += 1;
+            return None;
+        }
+        self.tick_count = 0;
+        match self.game.bot_action() {
+            Some(action) => {
+                let seat = self.game.current_player_seat.clone();
+                if let Err(err) = self.game.apply(seat, action) {
+                    self.notice = Some(err.to_string());
+                }
+            }
+            None => {
+                self.game.advance();
+            }
         }
         None
     }
 }
 
-fn build_card_lines(hand: &[Card]) -> Text {
+// render a team's name and scores to a score board
+fn render_score_board(frame: &mut Frame, team: &Team, score_area: &ScoreBoardLayout) {
+    frame.render_widget(
+        Paragraph::new(team.name.clone()).alignment(Alignment::Center),
+        score_area.team_name_area,
+    );
+    frame.render_widget(
+        Paragraph::new(team.game_score.to_string()).alignment(Alignment::Center),
+        score_area.game_score_area,
+    );
+    frame.render_widget(
+        Paragraph::new(team.hand_score.to_string()).alignment(Alignment::Center),
+        score_area.hand_score_area,
+    );
+}
+
+// build a player's name line, marking the dealer and the maker
+fn player_name_line(game: &Game, seat: Seat) -> String {
+    let mut name = game.get_player_in_seat(seat.clone()).name.clone();
+    if game.table.dealer_seat == seat && !matches!(game.state, GameState::PickingDealer) {
+        name.push_str(" (D)");
+    }
+    if game.table.maker_seat == Some(seat.clone()) {
+        if let Some(bid) = game.table.bid {
+            name.push_str(&format!(" (bid {})", bid));
+        }
+        if game.high_suit != Suit::_None {
+            name.push_str(&format!(" ({})", game.high_suit.get_symbol()));
+        }
+        // nobody knows who holds the called card until it is played
+        if let Some(partner_suit) = game.table.partner_suit {
+            let unknown = if game.table.partner_revealed { "" } else { "?" };
+            name.push_str(&format!(
+                " (calls {}{})",
+                partner_suit.get_symbol(),
+                unknown
+            ));
+        }
+    }
+    if game.table.partner_revealed && game.table.partner_seat == Some(seat.clone()) {
+        name.push_str(" (partner)");
+    }
+    if game.table.alone_seat == Some(seat.clone())
+        || game.table.defender_alone_seat == Some(seat.clone())
+    {
+        name.push_str(" (alone)");
+    }
+    if game.is_sitting_out(&seat) {
+        name.push_str(" (out)");
+    }
+    name
+}
+
+// build the prompt for the decision the user needs to make
+fn user_prompt(game: &Game) -> Option<String> {
+    if matches!(game.state, GameState::GameOver) {
+        return Some("(Enter) return to menu".to_string());
+    }
+    if !game.is_awaiting_user() {
+        return None;
+    }
+    match game.state {
+        GameState::CallingMisdeal => {
+            Some("No face cards or aces, call a misdeal? (y) yes  (n) no".to_string())
+        }
+        GameState::SwappingFarmersHand => {
+            Some("Swap your nines and tens with the kitty? (y) yes  (n) no".to_string())
+        }
+        GameState::CallingPickup => {
+            let mut prompt = if game.current_player_seat == game.table.dealer_seat {
+                "(y) pick it up".to_string()
+            } else {
+                "(y) order up".to_string()
+            };
+            if game.can_go_alone() {
+                prompt.push_str("  (a) alone");
+            }
+            prompt.push_str("  (n) pass");
+            Some(prompt)
+        }
+        GameState::CallingAce => {
+            let mut prompt = "Call for a partner".to_string();
+            for suit in SUIT_VARIANTS {
+                if *suit != game.high_suit {
+                    let key = suit.get_name().to_lowercase().remove(0);
+                    prompt.push_str(&format!(" ({key}){}", suit.get_symbol()));
+                }
+            }
+            Some(prompt)
+        }
+        GameState::PassingToLoner => {
+            let loner = game
+                .table
+                .alone_seat
+                .clone()
+                .map(|seat| game.get_player_in_seat(seat).name.clone())
+                .unwrap_or_default();
+            Some(format!(
+                "Pass your best card to {} (1-{})",
+                loner,
+                game.current_player().hand.len()
+            ))
+        }
+        GameState::DealerDiscarding | GameState::LonerDiscarding => Some(format!(
+            "Choose a card to discard (1-{})",
+            game.current_player().hand.len()
+        )),
+        GameState::CallingHighSuit => {
+            let mut prompt = "Call".to_string();
+            for suit in SUIT_VARIANTS {
+                if *suit != game.turned_down_suit {
+                    let key = suit.get_name().to_lowercase().remove(0);
+                    prompt.push_str(&format!(" ({key}){}", suit.get_symbol()));
+                }
+            }
+            if game.can_call_no_trump() {
+                prompt.push_str(" (t) no trump");
+            }
+            if game.can_go_alone() {
+                prompt.push_str("  (shift) alone");
+            }
+            if !game.is_dealer_stuck() {
+                prompt.push_str("  (n) pass");
+            }
+            Some(prompt)
+        }
+        GameState::Bidding => {
+            let mut prompt = match game.min_bid() {
+                Some(Bid::Tricks(tricks)) => {
+                    format!("Bid ({}-{})", tricks, game.rules.mode.hand_size())
+                }
+                _ => "Bid".to_string(),
+            };
+            if game.can_bid(Bid::Pepper) {
+                prompt.push_str("  (a) pepper");
+            }
+            if !game.is_dealer_stuck() {
+                prompt.push_str("  (n) pass");
+            }
+            Some(prompt)
+        }
+        GameState::NamingTrump => {
+            let mut prompt = "Name trump".to_string();
+            for suit in SUIT_VARIANTS {
+                let key = suit.get_name().to_lowercase().remove(0);
+                prompt.push_str(&format!(" ({key}){}", suit.get_symbol()));
+            }
+            if game.can_name_no_trump() {
+                prompt.push_str("  (n) no trump");
+            }
+            if game.can_name_alone() {
+                prompt.push_str("  (shift) alone");
+            }
+            Some(prompt)
+        }
+        GameState::DefendingAlone => Some("Defend alone? (y) yes  (n) no".to_string()),
+        GameState::PlayingHand => {
+            let action = if game.table.trick.plays.is_empty() {
+                "Lead"
+            } else {
+                "Play"
+            };
+            let mut prompt = format!("{} a card (1-{})", action, game.current_player().hand.len());
+            if game.table.trick.plays.is_empty() {
+                prompt.push_str("  (c) claim the rest");
+            }
+            if game.rules.renege == RenegeRule::CallOut {
+                prompt.push_str("  (r) call renege");
+            }
+            Some(prompt)
+        }
+        _ => None,
+    }
+}
+
+// how long to wait before the next bot action or automatic step
+fn step_delay_ticks(game: &Game) -> u64 {
+    let black_jack_showing = game
+        .table
+        .first_dealer_cards
+        .last()
+        .is_some_and(|(_, card)| card.is_black_jack());
+    match game.state {
+        GameState::PickingDealer if game.hand_num > 0 => 0,
+        // leave the black jack showing for a moment before the real deal
+        GameState::PickingDealer if black_jack_showing => TRICK_DELAY_TICKS,
+        GameState::PickingDealer => PICK_DELAY_TICKS,
+        GameState::DealingHand => DEAL_DELAY_TICKS,
+        // leave the trick on the table for a moment before clearing it
+        GameState::PlayingHand if game.is_trick_complete() => TRICK_DELAY_TICKS,
+        _ => BOT_DELAY_TICKS,
+    }
+}
+
+// map a key to the decision the user is being asked to make
+fn user_action_from_key(game: &Game, code: KeyCode) -> Option<GameAction> {
+    let yes_no = match code {
+        KeyCode::Char('y') | KeyCode::Char('Y') => Some(true),
+        KeyCode::Char('n') | KeyCode::Char('N') => Some(false),
+        _ => None,
+    };
+    match game.state {
+        GameState::CallingPickup => match code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => Some(GameAction::OrderUp { alone: false }),
+            KeyCode::Char('a') | KeyCode::Char('A') => Some(GameAction::OrderUp { alone: true }),
+            KeyCode::Char('n') | KeyCode::Char('N') => Some(GameAction::Pass),
+            _ => None,
+        },
+        GameState::DealerDiscarding | GameState::LonerDiscarding => {
+            card_idx_from_key(code).map(GameAction::Discard)
+        }
+        GameState::CallingAce => suit_from_key(code).map(GameAction::CallAce),
+        GameState::PassingToLoner => card_idx_from_key(code).map(GameAction::PassToLoner),
+        GameState::CallingMisdeal => yes_no.map(GameAction::CallMisdeal),
+        GameState::SwappingFarmersHand => yes_no.map(GameAction::SwapFarmersHand),
+        GameState::DefendingAlone => yes_no.map(GameAction::DefendAlone),
+        GameState::PlayingHand => match code {
+            KeyCode::Char('c') | KeyCode::Char('C') => Some(GameAction::ClaimTricks),
+            _ => card_idx_from_key(code).map(GameAction::PlayCard),
+        },
+        GameState::Bidding => match code {
+            KeyCode::Char('a') | KeyCode::Char('A') => Some(GameAction::Bid(Bid::Pepper)),
+            KeyCode::Char('n') | KeyCode::Char('N') => Some(GameAction::Pass),
+            _ => card_idx_from_key(code)
+                .map(|card_idx| GameAction::Bid(Bid::Tricks(card_idx as u8 + 1))),
+        },
+        GameState::NamingTrump => match code {
+            KeyCode::Char('n') | KeyCode::Char('N') => Some(GameAction::NameTrump {
+                suit: Suit::NoTrump,
+                alone: false,
+            }),
+            // holding shift names the suit alone
+            _ => suit_from_key(code).map(|suit| GameAction::NameTrump {
+                suit,
+                alone: matches!(code, KeyCode::Char(c) if c.is_ascii_uppercase()),
+            }),
+        },
+        GameState::CallingHighSuit => match code {
+            KeyCode::Char('n') | KeyCode::Char('N') => Some(GameAction::Pass),
+            KeyCode::Char('t') => Some(GameAction::CallSuit {
+                suit: Suit::NoTrump,
+                alone: false,
+            }),
+            KeyCode::Char('T') => Some(GameAction::CallSuit {
+                suit: Suit::NoTrump,
+                alone: true,
+            }),
+            // holding shift calls the suit alone
+            _ => suit_from_key(code).map(|suit| GameAction::CallSuit {
+                suit,
+                alone: matches!(code, KeyCode::Char(c) if c.is_ascii_uppercase()),
+            }),
+        },
+        _ => None,
+    }
+}
+
+// map a suit initial key to the suit
+fn suit_from_key(code: KeyCode) -> Option<Suit> {
+    match code {
+        KeyCode::Char(c) => SUIT_VARIANTS
+            .iter()
+            .find(|suit| {
+                suit.get_name()
+                    .to_lowercase()
+                    .starts_with(c.to_ascii_lowercase())
+            })
+            .copied(),
+        _ => None,
+    }
+}
+
+// map a number key to the index of a card in hand
+fn card_idx_from_key(code: KeyCode) -> Option<usize> {
+    match code {
+        KeyCode::Char(c) => c
+            .to_digit(10)
+            .filter(|digit| *digit > 0)
+            .map(|digit| digit as usize - 1),
+        _ => None,
+    }
+}
+
+fn build_card_back_lines(num_cards: usize) -> Text<'static> {
+    let lines: Vec<Line> = (0..num_cards)
+        .map(|_| Line::from("░".repeat(SMALL_CARD_WIDTH)))
+        .collect();
+    Text::from(lines)
+}
+
+fn build_card_lines(hand: &[Card]) -> Text<'_> {
     let mut lines = vec![];
     for card in hand.iter().copied() {
         let line = Line::from(card.get_name());
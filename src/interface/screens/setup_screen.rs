@@ -1,4 +1,7 @@
-use crate::engine::game::Game;
+use crate::engine::{
+    game::Game,
+    rules::{DeckRule, RulePreset, RuleSet},
+};
 use crate::interface::{
     components::{ascii_text::SETUP_HEADER, inputs::InputStyle},
     interface_callback::InterfaceCallback,
@@ -10,6 +13,7 @@ use name_maker::RandomNameGenerator;
 use ratatui::style::Color;
 use ratatui::{
     style::{Modifier, Style},
+    text::{Line, Text},
     widgets::{block::Block, BorderType, Borders, Paragraph},
     Frame,
 };
@@ -18,6 +22,12 @@ use tui_textarea::TextArea;
 
 const MIN_NAME_LENGTH: usize = 3;
 const MAX_NAME_LENGTH: usize = 12;
+const MIN_WINNING_SCORE: u8 = 5;
+const MAX_WINNING_SCORE: u8 = 15;
+const MIN_RENEGE_PENALTY: u8 = 1;
+const MAX_RENEGE_PENALTY: u8 = 4;
+// number of house rules listed below the preset
+const NUM_RULES: u16 = 13;
 
 // setup screen state repr
 #[derive(Debug, Default, PartialOrd, PartialEq)]
@@ -25,6 +35,21 @@ pub enum SetupScreenState {
     #[default]
     UserName,
     TeamName,
+    Mode,
+    Preset,
+    AllPass,
+    WinningScore,
+    LonersAllowed,
+    DefendAlone,
+    Renege,
+    RenegePenalty,
+    FarmersHand,
+    AceNoFace,
+    Deck,
+    Joker,
+    NoTrump,
+    ThrowIn,
+    AutoPlay,
     Confirm,
 }
 
@@ -32,14 +57,44 @@ impl SetupScreenState {
     pub fn next(&self) -> Self {
         match self {
             SetupScreenState::UserName => SetupScreenState::TeamName,
-            SetupScreenState::TeamName => SetupScreenState::Confirm,
+            SetupScreenState::TeamName => SetupScreenState::Mode,
+            SetupScreenState::Mode => SetupScreenState::Preset,
+            SetupScreenState::Preset => SetupScreenState::AllPass,
+            SetupScreenState::AllPass => SetupScreenState::WinningScore,
+            SetupScreenState::WinningScore => SetupScreenState::LonersAllowed,
+            SetupScreenState::LonersAllowed => SetupScreenState::DefendAlone,
+            SetupScreenState::DefendAlone => SetupScreenState::Renege,
+            SetupScreenState::Renege => SetupScreenState::RenegePenalty,
+            SetupScreenState::RenegePenalty => SetupScreenState::FarmersHand,
+            SetupScreenState::FarmersHand => SetupScreenState::AceNoFace,
+            SetupScreenState::AceNoFace => SetupScreenState::Deck,
+            SetupScreenState::Deck => SetupScreenState::Joker,
+            SetupScreenState::Joker => SetupScreenState::NoTrump,
+            SetupScreenState::NoTrump => SetupScreenState::ThrowIn,
+            SetupScreenState::ThrowIn => SetupScreenState::AutoPlay,
+            SetupScreenState::AutoPlay => SetupScreenState::Confirm,
             SetupScreenState::Confirm => SetupScreenState::Confirm,
         }
     }
 
     pub fn prev(&self) -> Self {
         match self {
-            SetupScreenState::Confirm => SetupScreenState::TeamName,
+            SetupScreenState::Confirm => SetupScreenState::AutoPlay,
+            SetupScreenState::AutoPlay => SetupScreenState::ThrowIn,
+            SetupScreenState::ThrowIn => SetupScreenState::NoTrump,
+            SetupScreenState::NoTrump => SetupScreenState::Joker,
+            SetupScreenState::Joker => SetupScreenState::Deck,
+            SetupScreenState::Deck => SetupScreenState::AceNoFace,
+            SetupScreenState::AceNoFace => SetupScreenState::FarmersHand,
+            SetupScreenState::FarmersHand => SetupScreenState::RenegePenalty,
+            SetupScreenState::RenegePenalty => SetupScreenState::Renege,
+            SetupScreenState::Renege => SetupScreenState::DefendAlone,
+            SetupScreenState::DefendAlone => SetupScreenState::LonersAllowed,
+            SetupScreenState::LonersAllowed => SetupScreenState::WinningScore,
+            SetupScreenState::WinningScore => SetupScreenState::AllPass,
+            SetupScreenState::AllPass => SetupScreenState::Preset,
+            SetupScreenState::Preset => SetupScreenState::Mode,
+            SetupScreenState::Mode => SetupScreenState::TeamName,
             SetupScreenState::TeamName => SetupScreenState::UserName,
             SetupScreenState::UserName => SetupScreenState::UserName,
         }
@@ -52,10 +107,12 @@ pub struct SetupScreen {
     pub state: SetupScreenState,
     pub user_name_textarea: TextArea<'static>,
     pub team_name_textarea: TextArea<'static>,
+    pub rules: RuleSet,
+    pub seed: Option<u64>,
 }
 
 impl SetupScreen {
-    pub fn new() -> Self {
+    pub fn new(seed: Option<u64>) -> Self {
         // initialize text areas
         let mut user_name_textarea = TextArea::default();
         user_name_textarea.set_block(build_textarea_block("User Name".to_string()));
@@ -69,6 +126,8 @@ impl SetupScreen {
             state: SetupScreenState::default(),
             user_name_textarea,
             team_name_textarea,
+            rules: RulePreset::default().rules(),
+            seed,
         }
     }
 
@@ -80,18 +139,90 @@ impl SetupScreen {
         match self.state {
             SetupScreenState::UserName => &mut self.user_name_textarea,
             SetupScreenState::TeamName => &mut self.team_name_textarea,
-            SetupScreenState::Confirm => unreachable!(),
+            _ => unreachable!(),
+        }
+    }
+
+    // whether the mode forces the rule on the given row, so changing it would have no effect
+    fn is_forced(&self, state: &SetupScreenState) -> bool {
+        let rules = &self.rules;
+        match state {
+            SetupScreenState::LonersAllowed => {
+                let toggled = RuleSet {
+                    loners_allowed: !rules.loners_allowed,
+                    ..rules.clone()
+                };
+                toggled.uses_loners() == rules.uses_loners()
+            }
+            SetupScreenState::DefendAlone => {
+                let toggled = RuleSet {
+                    defend_alone: !rules.defend_alone,
+                    ..rules.clone()
+                };
+                toggled.uses_defend_alone() == rules.uses_defend_alone()
+            }
+            SetupScreenState::FarmersHand => {
+                let toggled = RuleSet {
+                    farmers_hand: !rules.farmers_hand,
+                    ..rules.clone()
+                };
+                toggled.uses_farmers_hand() == rules.uses_farmers_hand()
+            }
+            SetupScreenState::Deck => {
+                let decks = [
+                    DeckRule::TwentyFour,
+                    DeckRule::TwentyEight,
+                    DeckRule::ThirtyTwo,
+                ];
+                decks.into_iter().all(|deck| {
+                    let chosen = RuleSet {
+                        deck,
+                        ..rules.clone()
+                    };
+                    chosen.uses_deck() == rules.uses_deck()
+                })
+            }
+            SetupScreenState::Joker => {
+                let toggled = RuleSet {
+                    joker: !rules.joker,
+                    ..rules.clone()
+                };
+                toggled.uses_joker() == rules.uses_joker()
+            }
+            _ => false,
+        }
+    }
+
+    // a house rule row, a rule forced by the mode shows the value in play and can't be changed
+    fn build_rule_line(
+        &self,
+        title: &str,
+        value: String,
+        state: SetupScreenState,
+    ) -> Line<'static> {
+        let style = active_style(self.state == state);
+        if self.is_forced(&state) {
+            Line::from(format!(" {title:<15}{:>23} ", format!("{value} (forced)")))
+                .style(style.add_modifier(Modifier::DIM))
+        } else {
+            Line::from(format!(" {title:<15}{:>23} ", format!("< {value} >"))).style(style)
         }
     }
 
     fn init_game(&mut self) -> Game {
+        let mut player_names = vec![self.user_name_textarea.lines()[0].trim().to_string()];
+        for _ in 1..self.rules.mode.seats().len() {
+            player_names.push(generate_player_name());
+        }
         Game::new(
-            self.user_name_textarea.lines()[0].trim().to_string(),
-            generate_player_name(),
-            generate_player_name(),
-            generate_player_name(),
-            self.team_name_textarea.lines()[0].trim().to_string(),
-            "Bad Guys".to_string(),
+            player_names,
+            vec![
+                self.team_name_textarea.lines()[0].trim().to_string(),
+                "Bad Guys".to_string(),
+                "Other Guys".to_string(),
+            ],
+            self.rules.clone(),
+            self.seed.unwrap_or_else(rand::random),
         )
     }
 }
@@ -99,7 +230,7 @@ impl SetupScreen {
 impl Screen for SetupScreen {
     // render the setup screen to the frame
     fn render(&mut self, frame: &mut Frame) -> Result<()> {
-        let menu_layout = MenuLayout::new(frame, 3);
+        let menu_layout = MenuLayout::with_option_heights(frame, &[3, 3, 3, 3, NUM_RULES + 2, 3]);
 
         // screen title
         frame.render_widget(
@@ -120,7 +251,7 @@ impl Screen for SetupScreen {
                 deactivate_textarea(&mut self.user_name_textarea);
                 activate_textarea(&mut self.team_name_textarea);
             }
-            SetupScreenState::Confirm => {
+            _ => {
                 deactivate_textarea(&mut self.user_name_textarea);
                 deactivate_textarea(&mut self.team_name_textarea);
             }
@@ -134,6 +265,100 @@ impl Screen for SetupScreen {
             self.team_name_textarea.widget(),
             menu_layout.menu_option_areas[1],
         );
+        frame.render_widget(
+            build_option_widget(
+                "Game Mode".to_string(),
+                self.rules.mode.to_string(),
+                self.state == SetupScreenState::Mode,
+            ),
+            menu_layout.menu_option_areas[2],
+        );
+        frame.render_widget(
+            build_option_widget(
+                "Rules".to_string(),
+                self.rules.preset().to_string(),
+                self.state == SetupScreenState::Preset,
+            ),
+            menu_layout.menu_option_areas[3],
+        );
+
+        // house rules, tweaking any of them makes the preset custom
+        let rule_lines = vec![
+            self.build_rule_line(
+                "All Pass",
+                self.rules.all_pass.to_string(),
+                SetupScreenState::AllPass,
+            ),
+            self.build_rule_line(
+                "Winning Score",
+                self.rules.winning_score.to_string(),
+                SetupScreenState::WinningScore,
+            ),
+            self.build_rule_line(
+                "Loners",
+                on_off(self.rules.uses_loners()),
+                SetupScreenState::LonersAllowed,
+            ),
+            self.build_rule_line(
+                "Defend Alone",
+                on_off(self.rules.uses_defend_alone()),
+                SetupScreenState::DefendAlone,
+            ),
+            self.build_rule_line(
+                "Renege",
+                self.rules.renege.to_string(),
+                SetupScreenState::Renege,
+            ),
+            self.build_rule_line(
+                "Renege Penalty",
+                self.rules.renege_penalty.to_string(),
+                SetupScreenState::RenegePenalty,
+            ),
+            self.build_rule_line(
+                "Farmer's Hand",
+                on_off(self.rules.uses_farmers_hand()),
+                SetupScreenState::FarmersHand,
+            ),
+            self.build_rule_line(
+                "Ace No Face",
+                on_off(self.rules.ace_no_face),
+                SetupScreenState::AceNoFace,
+            ),
+            self.build_rule_line(
+                "Deck",
+                self.rules.uses_deck().to_string(),
+                SetupScreenState::Deck,
+            ),
+            self.build_rule_line(
+                "Joker (Benny)",
+                on_off(self.rules.uses_joker()),
+                SetupScreenState::Joker,
+            ),
+            self.build_rule_line(
+                "No Trump",
+                on_off(self.rules.no_trump),
+                SetupScreenState::NoTrump,
+            ),
+            self.build_rule_line(
+                "Decided Hand",
+                self.rules.throw_in.to_string(),
+                SetupScreenState::ThrowIn,
+            ),
+            self.build_rule_line(
+                "Auto-Play",
+                on_off(self.rules.auto_play),
+                SetupScreenState::AutoPlay,
+            ),
+        ];
+        let rules_active =
+            self.state > SetupScreenState::Preset && self.state < SetupScreenState::Confirm;
+        frame.render_widget(
+            Paragraph::new(Text::from(rule_lines)).block(
+                build_textarea_block("House Rules".to_string())
+                    .border_style(active_style(rules_active)),
+            ),
+            menu_layout.menu_option_areas[4],
+        );
 
         // submit message
         // TODO: make this a button?
@@ -144,12 +369,12 @@ impl Screen for SetupScreen {
             {
                 frame.render_widget(
                     Paragraph::new("Ready to begin? (Enter)").centered(),
-                    menu_layout.menu_option_areas[2],
+                    menu_layout.menu_option_areas[5],
                 );
             } else {
                 frame.render_widget(
                     Paragraph::new("Enter valid values to continue.").centered(),
-                    menu_layout.menu_option_areas[2],
+                    menu_layout.menu_option_areas[5],
                 );
             }
         }
@@ -178,6 +403,99 @@ impl Screen for SetupScreen {
                     }
                     _ => self.set_state(self.state.next()),
                 },
+                // rule option inputs, a rule forced by the mode stays put
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.is_forced(&self.state) => {}
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::Mode =>
+                {
+                    self.rules.mode = self.rules.mode.next();
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::Preset =>
+                {
+                    self.rules = RuleSet {
+                        mode: self.rules.mode,
+                        auto_play: self.rules.auto_play,
+                        ..self.rules.preset().next().rules()
+                    };
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::AllPass =>
+                {
+                    self.rules.all_pass = self.rules.all_pass.next();
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::DefendAlone =>
+                {
+                    self.rules.defend_alone = !self.rules.defend_alone;
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::LonersAllowed =>
+                {
+                    self.rules.loners_allowed = !self.rules.loners_allowed;
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::FarmersHand =>
+                {
+                    self.rules.farmers_hand = !self.rules.farmers_hand;
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::AceNoFace =>
+                {
+                    self.rules.ace_no_face = !self.rules.ace_no_face;
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::Deck =>
+                {
+                    // skip over decks the mode swaps for the one already in play
+                    let deck = self.rules.uses_deck();
+                    self.rules.deck = self.rules.deck.next();
+                    while self.rules.uses_deck() == deck {
+                        self.rules.deck = self.rules.deck.next();
+                    }
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::Joker =>
+                {
+                    self.rules.joker = !self.rules.joker;
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::NoTrump =>
+                {
+                    self.rules.no_trump = !self.rules.no_trump;
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::ThrowIn =>
+                {
+                    self.rules.throw_in = self.rules.throw_in.next();
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::AutoPlay =>
+                {
+                    self.rules.auto_play = !self.rules.auto_play;
+                }
+                KeyCode::Left | KeyCode::Right | KeyCode::Char(' ')
+                    if self.state == SetupScreenState::Renege =>
+                {
+                    self.rules.renege = self.rules.renege.next();
+                }
+                KeyCode::Left if self.state == SetupScreenState::WinningScore => {
+                    self.rules.winning_score =
+                        (self.rules.winning_score - 1).max(MIN_WINNING_SCORE);
+                }
+                KeyCode::Right if self.state == SetupScreenState::WinningScore => {
+                    self.rules.winning_score =
+                        (self.rules.winning_score + 1).min(MAX_WINNING_SCORE);
+                }
+                KeyCode::Left if self.state == SetupScreenState::RenegePenalty => {
+                    self.rules.renege_penalty =
+                        (self.rules.renege_penalty - 1).max(MIN_RENEGE_PENALTY);
+                }
+                KeyCode::Right if self.state == SetupScreenState::RenegePenalty => {
+                    self.rules.renege_penalty =
+                        (self.rules.renege_penalty + 1).min(MAX_RENEGE_PENALTY);
+                }
                 _ => {
                     // text area inputs
                     // TODO: only allow alphanumeric keys in text areas
@@ -224,6 +542,28 @@ fn deactivate_textarea(textarea: &mut TextArea<'_>) {
     }
 }
 
+fn active_style(is_active: bool) -> Style {
+    if is_active {
+        Style::default().fg(Color::Blue)
+    } else {
+        Style::default()
+    }
+}
+
+fn build_option_widget(title: String, value: String, is_active: bool) -> Paragraph<'static> {
+    Paragraph::new(format!("< {value} >"))
+        .centered()
+        .block(build_textarea_block(title).border_style(active_style(is_active)))
+}
+
+fn on_off(value: bool) -> String {
+    if value {
+        "On".to_string()
+    } else {
+        "Off".to_string()
+    }
+}
+
 fn validate_textarea(textarea: &mut TextArea<'_>) -> bool {
     let text = textarea.lines()[0].trim();
     if text.len() < MIN_NAME_LENGTH || text.len() > MAX_NAME_LENGTH {
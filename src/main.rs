@@ -5,9 +5,9 @@ use std::io::Result;
 
 fn main() -> Result<()> {
     // parse cli args
-    let _ = Args::parse();
+    let args = Args::parse();
 
     // create and run the application
-    App::new().run()?;
+    App::new(args.seed).run()?;
     Ok(())
 }